@@ -0,0 +1,154 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{AocError, Input, Verbosity};
+
+/// Command line arguments, consumed piece by piece.
+///
+/// Flags and options should be taken before positional arguments, so that
+/// option values are not mistaken for positionals.
+pub struct Args {
+    usage: String,
+    args: Vec<String>,
+}
+
+impl Args {
+    pub fn from_env(usage: &str) -> Self {
+        let mut args = std::env::args();
+        let program = args.next().unwrap_or_default();
+        Self::new(&program, args, usage)
+    }
+
+    pub fn new(program: &str, args: impl IntoIterator<Item = String>, usage: &str) -> Self {
+        Self {
+            usage: format!("{} {}", program, usage),
+            args: args.into_iter().collect(),
+        }
+    }
+
+    /// Removes every occurrence of `name`, returns whether there was any.
+    pub fn flag(&mut self, name: &str) -> bool {
+        let len = self.args.len();
+        self.args.retain(|arg| arg != name);
+        self.args.len() != len
+    }
+
+    /// Removes `-v`, `-vv`, ... and counts the `v`s.
+    pub fn verbosity(&mut self) -> Verbosity {
+        let level = self
+            .args
+            .iter()
+            .filter(|arg| is_verbosity(arg))
+            .map(|arg| arg.len() - 1)
+            .sum();
+        self.args.retain(|arg| !is_verbosity(arg));
+        Verbosity::from_level(level)
+    }
+
+    /// Removes `name <value>` and parses the value.
+    pub fn option<T>(&mut self, name: &str) -> Result<Option<T>, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let Some(i) = self.args.iter().position(|arg| arg == name) else {
+            return Ok(None);
+        };
+
+        if i + 1 >= self.args.len() {
+            return Err(self.error(format!("Missing value for {}", name)));
+        }
+
+        let value = self.args.remove(i + 1);
+        self.args.remove(i);
+        self.parse(name, &value).map(Some)
+    }
+
+    /// Removes the first argument that is not a flag and parses it.
+    pub fn positional<T>(&mut self, name: &str) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let i = self
+            .args
+            .iter()
+            .position(|arg| !is_flag(arg))
+            .ok_or_else(|| self.error(format!("Missing argument <{}>", name)))?;
+
+        let value = self.args.remove(i);
+        self.parse(name, &value)
+    }
+
+    /// `--example` selects the embedded example, otherwise the next
+    /// positional argument is a file path or `-` for stdin.
+    pub fn input(&mut self, example: &'static str) -> Result<Input, AocError> {
+        if self.flag("--example") {
+            return Ok(Input::Example(example));
+        }
+
+        self.positional::<String>("filename")
+            .map(|arg| Input::from_arg(&arg))
+    }
+
+    /// Fails if any argument was left unconsumed.
+    pub fn finish(&self) -> Result<(), AocError> {
+        match self.args.first() {
+            Some(arg) => Err(self.error(format!("Unexpected argument '{}'", arg))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn error(&self, message: String) -> AocError {
+        AocError::Usage {
+            usage: self.usage.clone(),
+            message,
+        }
+    }
+
+    fn parse<T>(&self, name: &str, value: &str) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        value
+            .parse::<T>()
+            .map_err(|e| self.error(format!("Invalid value '{}' for {}: {}", value, name, e)))
+    }
+}
+
+// `-` (stdin) and negative numbers are positionals, `-v` or `--name` are not
+fn is_flag(arg: &str) -> bool {
+    arg.starts_with("--")
+        || arg
+            .strip_prefix('-')
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(|ch| ch.is_ascii_alphabetic())
+}
+
+fn is_verbosity(arg: &str) -> bool {
+    arg.len() > 1 && arg.starts_with('-') && arg[1..].bytes().all(|b| b == b'v')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Args {
+        Args::new("test", args.iter().map(|arg| arg.to_string()), "")
+    }
+
+    #[test]
+    fn verbosity_counts_vs() {
+        assert_eq!(args(&["-"]).verbosity(), Verbosity::Quiet);
+        assert_eq!(args(&["-v", "-"]).verbosity(), Verbosity::Debug);
+        assert_eq!(args(&["-vv"]).verbosity(), Verbosity::Trace);
+        assert_eq!(args(&["-v", "-v", "-v"]).verbosity(), Verbosity::Trace);
+    }
+
+    #[test]
+    fn verbosity_leaves_other_args() {
+        let mut args = args(&["-v", "-", "--example"]);
+        args.verbosity();
+        assert_eq!(args.args, ["-", "--example"]);
+    }
+}
@@ -0,0 +1,47 @@
+use std::{fmt, io};
+
+pub enum AocError {
+    Usage { usage: String, message: String },
+    Io { source: String, error: io::Error },
+    Parse(String),
+    NoSolution(String),
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Usage { usage, message } => write!(f, "{}\nUsage: {}", message, usage),
+            Self::Io { source, error } => write!(f, "Failed to read '{}': {}", source, error),
+            Self::Parse(e) => write!(f, "ParseError: {}", e),
+            Self::NoSolution(e) => write!(f, "No solution: {}", e),
+        }
+    }
+}
+
+// `main` reports a returned error through `Debug`, so keep it human readable
+impl fmt::Debug for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for AocError {
+    fn from(e: String) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<&str> for AocError {
+    fn from(e: &str) -> Self {
+        Self::Parse(e.to_string())
+    }
+}
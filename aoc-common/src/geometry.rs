@@ -0,0 +1,208 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Grid coordinate, signed so that stepping off the edge is just out of bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    /// Clockwise from up, including diagonals.
+    pub const ADJACENT: [Point; 8] = [
+        Point::new(-1, 0),
+        Point::new(-1, 1),
+        Point::new(0, 1),
+        Point::new(1, 1),
+        Point::new(1, 0),
+        Point::new(1, -1),
+        Point::new(0, -1),
+        Point::new(-1, -1),
+    ];
+
+    pub const fn new(row: i32, col: i32) -> Self {
+        Self { row, col }
+    }
+
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.row - other.row).abs() + (self.col - other.col).abs()
+    }
+
+    pub fn chebyshev(self, other: Point) -> i32 {
+        (self.row - other.row)
+            .abs()
+            .max((self.col - other.col).abs())
+    }
+
+    /// Quarter turn clockwise around the origin, so up becomes right.
+    pub fn rotate_right(self) -> Self {
+        Self::new(self.col, -self.row)
+    }
+
+    /// Quarter turn counter-clockwise around the origin, so up becomes left.
+    pub fn rotate_left(self) -> Self {
+        Self::new(-self.col, self.row)
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((row, col): (i32, i32)) -> Self {
+        Self::new(row, col)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self {
+        self + rhs.delta()
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Sub<Direction> for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Direction) -> Self {
+        self - rhs.delta()
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self {
+        Self::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.row, -self.col)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Self::Up => Point::new(-1, 0),
+            Self::Right => Point::new(0, 1),
+            Self::Down => Point::new(1, 0),
+            Self::Left => Point::new(0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// `^`, `>`, `v` or `<`.
+    pub fn from_arrow(ch: char) -> Option<Self> {
+        match ch {
+            '^' => Some(Self::Up),
+            '>' => Some(Self::Right),
+            'v' => Some(Self::Down),
+            '<' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 4);
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(b - a + a, b);
+    }
+
+    #[test]
+    fn rotation_matches_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.delta().rotate_right(), dir.turn_right().delta());
+            assert_eq!(dir.delta().rotate_left(), dir.turn_left().delta());
+            assert_eq!(-dir.delta(), dir.opposite().delta());
+            assert_eq!(Direction::from_arrow(dir.arrow()), Some(dir));
+        }
+    }
+}
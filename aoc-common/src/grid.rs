@@ -0,0 +1,246 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{Direction, Point};
+
+/// Rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Fails on rows of different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        if let Some(i) = ragged_row(&rows) {
+            return Err(format!(
+                "row {}: expected {} columns, found {}",
+                i + 1,
+                rows[0].len(),
+                rows[i].len()
+            ));
+        }
+
+        Ok(Self::flatten(rows))
+    }
+
+    /// One row per line, one cell per character, errors name the line.
+    pub fn parse_with<F, E>(input: &str, mut f: F) -> Result<Self, String>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: fmt::Display,
+    {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.chars()
+                    .map(&mut f)
+                    .collect::<Result<Vec<T>, E>>()
+                    .map_err(|e| format!("line {}: {}", i + 1, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(i) = ragged_row(&rows) {
+            return Err(format!(
+                "line {}: expected {} columns, found {}",
+                i + 1,
+                rows[0].len(),
+                rows[i].len()
+            ));
+        }
+
+        Ok(Self::flatten(rows))
+    }
+
+    // rows already checked to be the same length
+    fn flatten(rows: Vec<Vec<T>>) -> Self {
+        Self {
+            rows: rows.len(),
+            cols: rows.first().map_or(0, Vec::len),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, Point { row, col }: Point) -> bool {
+        row >= 0 && col >= 0 && (row as usize) < self.rows && (col as usize) < self.cols
+    }
+
+    fn offset(&self, pos: Point) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.row as usize * self.cols + pos.col as usize)
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let cols = self.cols;
+        (0..self.rows * cols).map(move |i| Point::new((i / cols) as i32, (i % cols) as i32))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Up, right, down, left, skipping the ones off the grid.
+    pub fn neighbours4(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| pos + dir)
+            .filter(|&next| self.contains(next))
+    }
+
+    /// Clockwise from up, including diagonals, skipping the ones off the grid.
+    pub fn neighbours8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Point::ADJACENT
+            .into_iter()
+            .map(move |delta| pos + delta)
+            .filter(|&next| self.contains(next))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, v)| *v == value).map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, v)| *v == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+fn ragged_row<T>(rows: &[Vec<T>]) -> Option<usize> {
+    let cols = rows.first().map_or(0, Vec::len);
+    rows.iter().position(|row| row.len() != cols)
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        match self.get(pos) {
+            Some(value) => value,
+            None => panic!("{:?} is outside of {}x{} grid", pos, self.rows, self.cols),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get_mut(pos) {
+            Some(value) => value,
+            None => panic!("{:?} is outside of {}x{} grid", pos, rows, cols),
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, Ok::<char, String>)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(row) {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &str = "ab.\n.#c\n...";
+
+    #[test]
+    fn parse_and_display() {
+        let grid: Grid<char> = GRID.parse().unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (3, 3));
+        assert_eq!(grid.to_string(), GRID);
+    }
+
+    #[test]
+    fn ragged_rows_name_the_line() {
+        let err = "abc\nab\nabc".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(err, "line 2: expected 3 columns, found 2");
+    }
+
+    #[test]
+    fn bounds_checked_get() {
+        let grid: Grid<char> = GRID.parse().unwrap();
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&'#'));
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 3)), None);
+        assert_eq!(grid.find(&'c'), Some(Point::new(1, 2)));
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid: Grid<char> = GRID.parse().unwrap();
+        assert_eq!(
+            grid.neighbours4(Point::ORIGIN).collect::<Vec<_>>(),
+            [Point::new(0, 1), Point::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours8(Point::ORIGIN).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+    }
+}
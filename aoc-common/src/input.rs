@@ -0,0 +1,66 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+};
+
+use crate::AocError;
+
+/// Where the puzzle input comes from.
+#[derive(Debug, Clone)]
+pub enum Input {
+    File(PathBuf),
+    Stdin,
+    Example(&'static str),
+}
+
+impl Input {
+    /// `-` reads from stdin, anything else is a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Self::File(path) => path.display().to_string(),
+            Self::Stdin => "<stdin>".to_string(),
+            Self::Example(_) => "<example>".to_string(),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, AocError> {
+        match self {
+            Self::File(path) => std::fs::read_to_string(path).map_err(|e| self.error(e)),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| self.error(e))?;
+                Ok(input)
+            }
+            Self::Example(example) => Ok(example.to_string()),
+        }
+    }
+
+    pub fn lines(&self) -> Result<io::Lines<Box<dyn BufRead>>, AocError> {
+        let reader: Box<dyn BufRead> = match self {
+            Self::File(path) => {
+                Box::new(BufReader::new(File::open(path).map_err(|e| self.error(e))?))
+            }
+            Self::Stdin => Box::new(io::stdin().lock()),
+            Self::Example(example) => Box::new(example.as_bytes()),
+        };
+        Ok(reader.lines())
+    }
+
+    fn error(&self, error: io::Error) -> AocError {
+        AocError::Io {
+            source: self.name(),
+            error,
+        }
+    }
+}
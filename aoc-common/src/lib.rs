@@ -0,0 +1,18 @@
+mod args;
+mod error;
+mod geometry;
+mod grid;
+mod input;
+pub mod log;
+#[doc(hidden)]
+pub mod rng;
+pub mod search;
+mod solution;
+
+pub use args::Args;
+pub use error::AocError;
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use input::Input;
+pub use log::{set_verbosity, Verbosity};
+pub use solution::{Answer, Param, Params, Part, Solution};
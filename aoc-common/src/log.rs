@@ -0,0 +1,58 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much diagnostic output goes to stderr, quiet unless `-v` or `-vv`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet,
+    Debug,
+    Trace,
+}
+
+impl Verbosity {
+    pub fn from_level(level: usize) -> Self {
+        match level {
+            0 => Self::Quiet,
+            1 => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Quiet as u8);
+
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(verbosity: Verbosity) -> bool {
+    verbosity as u8 <= VERBOSITY.load(Ordering::Relaxed)
+}
+
+/// Prints `warning: ...` to stderr whatever the verbosity, for input that
+/// parsed but maybe not as meant.
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        eprintln!("warning: {}", format_args!($($arg)*));
+    };
+}
+
+/// Prints to stderr with `-v` or more.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::Verbosity::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Prints to stderr with `-vv`, meant for per-step output.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::Verbosity::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,36 @@
+/// A seeded xorshift generator for tests, reproducible without a dependency.
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// `seed` must not be zero, xorshift never leaves the zero state.
+    pub fn new(seed: u64) -> Self {
+        assert_ne!(seed, 0, "xorshift seed must not be zero");
+        Self { state: seed }
+    }
+
+    /// The next number below `bound`.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state % bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = XorShift::new(42);
+        let mut b = XorShift::new(42);
+        for bound in 1..100 {
+            let n = a.below(bound);
+            assert!(n < bound);
+            assert_eq!(n, b.below(bound));
+        }
+    }
+}
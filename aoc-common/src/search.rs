@@ -0,0 +1,304 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A cheapest way to the goal, `states` runs from the start to the goal inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Every cheapest way to the goal, as a predecessor graph walked back from the goals.
+#[derive(Debug, Clone)]
+pub struct AllPaths<S, C> {
+    pub cost: C,
+    goals: Vec<S>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C> AllPaths<S, C> {
+    /// All states lying on at least one cheapest path.
+    pub fn states(&self) -> HashSet<S> {
+        let mut seen: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut stack = self.goals.clone();
+
+        while let Some(state) = stack.pop() {
+            for prev in self.predecessors.get(&state).into_iter().flatten() {
+                if seen.insert(prev.clone()) {
+                    stack.push(prev.clone());
+                }
+            }
+        }
+
+        seen
+    }
+}
+
+// min-heap entry, ordered by estimated total cost only
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn reconstruct<S: Clone + Eq + Hash>(predecessors: &HashMap<S, S>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(prev) = predecessors.get(states.last().unwrap()) {
+        states.push(prev.clone());
+    }
+    states.reverse();
+    states
+}
+
+/// Fewest steps from `start` to a state satisfying `is_goal`.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut predecessors = HashMap::new();
+    let mut visited = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            let states = reconstruct(&predecessors, state);
+            return Some(Path {
+                cost: states.len() - 1,
+                states,
+            });
+        }
+
+        for next in neighbours(&state) {
+            if visited.insert(next.clone()) {
+                predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Cheapest path, `neighbours` yields each next state with the cost of the step.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` that must never overestimate.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut predecessors = HashMap::new();
+    let mut best = HashMap::from([(start.clone(), C::default())]);
+    let mut heap = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+
+    while let Some(Entry { cost, state, .. }) = heap.pop() {
+        if best.get(&state).is_some_and(|&b| cost > b) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            return Some(Path {
+                cost,
+                states: reconstruct(&predecessors, state),
+            });
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if best.get(&next).is_none_or(|&b| next_cost < b) {
+                best.insert(next.clone(), next_cost);
+                predecessors.insert(next.clone(), state.clone());
+                heap.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Like [`dijkstra`], but keeps every predecessor that ties for the cheapest cost.
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<AllPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut best = HashMap::from([(start.clone(), C::default())]);
+    let mut heap = BinaryHeap::from([Entry {
+        priority: C::default(),
+        cost: C::default(),
+        state: start,
+    }]);
+    let mut found: Option<AllPaths<S, C>> = None;
+
+    while let Some(Entry { cost, state, .. }) = heap.pop() {
+        if best.get(&state).is_some_and(|&b| cost > b) {
+            continue;
+        }
+
+        if let Some(found) = &mut found {
+            if cost > found.cost {
+                break;
+            }
+        }
+
+        if is_goal(&state) {
+            found
+                .get_or_insert_with(|| AllPaths {
+                    cost,
+                    goals: vec![],
+                    predecessors: HashMap::new(),
+                })
+                .goals
+                .push(state);
+            continue;
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            match best.get(&next).map(|&b| next_cost.cmp(&b)) {
+                Some(Ordering::Greater) => {}
+                Some(Ordering::Equal) => predecessors.entry(next).or_default().push(state.clone()),
+                _ => {
+                    best.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    heap.push(Entry {
+                        priority: next_cost,
+                        cost: next_cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+
+    found.map(|found| AllPaths {
+        predecessors,
+        ..found
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point};
+
+    const MAZE: &str = "\
+...#
+.#..
+...#
+#...";
+
+    fn open(grid: &Grid<char>, p: Point) -> Vec<Point> {
+        grid.neighbours4(p).filter(|&n| grid[n] == '.').collect()
+    }
+
+    #[test]
+    fn bfs_finds_fewest_steps() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let end = Point::new(3, 3);
+        let path = bfs(Point::ORIGIN, |&p| open(&grid, p), |&p| p == end).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states.first(), Some(&Point::ORIGIN));
+        assert_eq!(path.states.last(), Some(&end));
+        assert!(path.states.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+        assert!(bfs(
+            Point::ORIGIN,
+            |&p| open(&grid, p),
+            |&p| p == Point::new(0, 3)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let end = Point::new(3, 3);
+        let weighted = |p: &Point| {
+            open(&grid, *p)
+                .into_iter()
+                .map(|n| (n, if n.row == 1 { 5 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+
+        let cheapest = dijkstra(Point::ORIGIN, weighted, |&p| p == end).unwrap();
+        let guided = astar(Point::ORIGIN, weighted, |p| p.manhattan(end), |&p| p == end).unwrap();
+        assert_eq!(cheapest.cost, 10);
+        assert_eq!(guided.cost, cheapest.cost);
+    }
+
+    #[test]
+    fn all_paths_cover_every_tie() {
+        let grid: Grid<char> = "...\n...".parse().unwrap();
+        let end = Point::new(1, 2);
+        let paths = dijkstra_all(
+            Point::ORIGIN,
+            |&p| open(&grid, p).into_iter().map(|n| (n, 1)),
+            |&p| p == end,
+        )
+        .unwrap();
+        assert_eq!(paths.cost, 3);
+        assert_eq!(paths.states().len(), 6);
+    }
+}
@@ -0,0 +1,147 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use crate::AocError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{}", n),
+            Self::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err("part must be 1 or 2".to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => f.write_str("1"),
+            Self::Two => f.write_str("2"),
+        }
+    }
+}
+
+/// Extra input a day needs besides the puzzle text, e.g. the grid size.
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub example: &'static str,
+}
+
+pub struct Params {
+    values: HashMap<&'static str, String>,
+}
+
+impl Params {
+    pub fn new(params: &[Param], example: bool) -> Self {
+        let values = params
+            .iter()
+            .map(|p| {
+                let value = if example { p.example } else { p.default };
+                (p.name, value.to_string())
+            })
+            .collect();
+        Self { values }
+    }
+
+    pub fn set(&mut self, name: &'static str, value: String) {
+        self.values.insert(name, value);
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let value = self
+            .values
+            .get(name)
+            .ok_or_else(|| format!("Unknown parameter {}", name))?;
+
+        value
+            .parse::<T>()
+            .map_err(|e| AocError::Parse(format!("Invalid {} '{}': {}", name, value, e)))
+    }
+}
+
+pub trait Solution: Sized {
+    const DAY: u8;
+    const EXAMPLE: &'static str;
+    const PARAMS: &'static [Param] = &[];
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+
+    fn parse(input: &str, params: &Params) -> Result<Self, AocError>;
+
+    /// The example `part` is checked against, `None` when the puzzle has none
+    /// that fits it.
+    fn example(part: Part) -> Option<&'static str> {
+        let _ = part;
+        Some(Self::EXAMPLE)
+    }
+
+    /// Problems in the input that didn't stop the parse, the runner prints
+    /// them once per input.
+    fn warnings(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn part1(&self) -> Result<Answer, AocError>;
+
+    fn part2(&self) -> Result<Answer, AocError>;
+
+    fn solve(&self, part: Part) -> Result<Answer, AocError> {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Known answers for our own inputs, kept in a small TOML file:
+//!
+//! ```toml
+//! [day-01]
+//! part1 = 2066446
+//! part2 = 24931009
+//!
+//! [day-17]
+//! part1 = "7,3,5,7,5,7,4,3,0"
+//! ```
+
+use std::{collections::HashMap, path::Path};
+
+use aoc_common::{Answer, AocError, Part};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail(Answer),
+    Unknown,
+}
+
+pub struct Answers {
+    answers: HashMap<(u8, Part), Answer>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        let content = std::fs::read_to_string(path).map_err(|error| AocError::Io {
+            source: path.display().to_string(),
+            error,
+        })?;
+
+        content
+            .parse()
+            .map_err(|e| AocError::Parse(format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Status {
+        match self.answers.get(&(day, part)) {
+            // compare the printed form, so `part1 = "42"` matches an integer answer
+            Some(expected) if expected.to_string() == answer.to_string() => Status::Pass,
+            Some(expected) => Status::Fail(expected.clone()),
+            None => Status::Unknown,
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = HashMap::new();
+        let mut day = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let error = |message: &str| format!("line {}: {}", i + 1, message);
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[') {
+                let n = section
+                    .strip_suffix(']')
+                    .and_then(|s| s.strip_prefix("day-"))
+                    .and_then(|n| n.parse::<u8>().ok())
+                    .filter(|n| (1..=25).contains(n))
+                    .ok_or_else(|| error("expected a [day-NN] section"))?;
+                day = Some(n);
+                continue;
+            }
+
+            let day = day.ok_or_else(|| error("answer outside of a [day-NN] section"))?;
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `partN = answer`"))?;
+
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => return Err(error(&format!("unknown key '{}'", key))),
+            };
+
+            let value = value.trim();
+            let answer = match value.strip_prefix('"') {
+                Some(text) => text
+                    .strip_suffix('"')
+                    .map(Answer::from)
+                    .ok_or_else(|| error("unterminated string"))?,
+                None => value
+                    .parse::<i128>()
+                    .map(Answer::Int)
+                    .map_err(|_| error(&format!("invalid answer '{}'", value)))?,
+            };
+
+            if answers.insert((day, part), answer).is_some() {
+                return Err(error(&format!(
+                    "duplicate answer for day {} part {}",
+                    day, part
+                )));
+            }
+        }
+
+        Ok(Self { answers })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+# our inputs
+[day-01]
+part1 = 11
+part2 = \"31\"
+
+[day-17]
+part1 = \"4,6,3,5,6,3,5,2,1,0\"
+";
+
+    #[test]
+    fn check_answers() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+        assert_eq!(answers.check(1, Part::One, &11.into()), Status::Pass);
+        assert_eq!(answers.check(1, Part::Two, &31.into()), Status::Pass);
+        assert_eq!(
+            answers.check(17, Part::One, &"4,6,3".into()),
+            Status::Fail("4,6,3,5,6,3,5,2,1,0".into())
+        );
+        assert_eq!(answers.check(17, Part::Two, &1.into()), Status::Unknown);
+    }
+
+    #[test]
+    fn parse_errors_have_line_numbers() {
+        let err = "[day-01]\npart3 = 1".parse::<Answers>().err().unwrap();
+        assert_eq!(err, "line 2: unknown key 'part3'");
+
+        let err = "part1 = 1".parse::<Answers>().err().unwrap();
+        assert_eq!(err, "line 1: answer outside of a [day-NN] section");
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::AocError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Times `iterations` calls of `f`, returning the result of the last one.
+pub fn measure<T>(
+    iterations: usize,
+    mut f: impl FnMut() -> Result<T, AocError>,
+) -> Result<(Stats, T), AocError> {
+    assert!(iterations > 0, "need at least one iteration");
+
+    let mut samples = Vec::with_capacity(iterations);
+    let mut result = None;
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let value = f()?;
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+
+    Ok((Stats::new(samples), result.unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn stats_odd_samples() {
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(5)));
+    }
+
+    #[test]
+    fn stats_even_samples() {
+        let stats = Stats::new(vec![ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(8)));
+    }
+}
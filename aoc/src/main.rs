@@ -0,0 +1,290 @@
+mod answers;
+mod bench;
+mod output;
+
+use std::{
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use aoc_common::{set_verbosity, warn, AocError, Args, Input, Params, Part, Solution};
+
+use answers::{Answers, Status};
+use output::{BenchRecord, Format, Record, Stage};
+
+const USAGE: &str = "[-v|-vv] <run|bench> [--day <1-25>] [--part <1|2>] [--input <filename|->] \
+     [--example] [--format <text|json>] [--answers <filename>] [--iterations <n>] \
+     [--<param> <value>]";
+
+const DEFAULT_ITERATIONS: usize = 10;
+const DEFAULT_ANSWERS: &str = "answers.toml";
+
+macro_rules! with_solution {
+    ($day:expr, $S:ident => $body:expr) => {
+        with_solution!(@dispatch $day, $S => $body;
+            1 => day_01::Day01, 2 => day_02::Day02, 3 => day_03::Day03, 4 => day_04::Day04,
+            5 => day_05::Day05, 6 => day_06::Day06, 7 => day_07::Day07, 8 => day_08::Day08,
+            9 => day_09::Day09, 10 => day_10::Day10, 11 => day_11::Day11, 12 => day_12::Day12,
+            13 => day_13::Day13, 14 => day_14::Day14, 15 => day_15::Day15, 16 => day_16::Day16,
+            17 => day_17::Day17, 18 => day_18::Day18, 19 => day_19::Day19, 20 => day_20::Day20,
+            21 => day_21::Day21, 22 => day_22::Day22, 23 => day_23::Day23, 24 => day_24::Day24,
+            25 => day_25::Day25
+        )
+    };
+    (@dispatch $day:expr, $S:ident => $body:expr; $($n:literal => $t:ty),*) => {
+        match $day {
+            $($n => {
+                type $S = $t;
+                $body
+            })*
+            day => unreachable!("day {} out of range", day),
+        }
+    };
+}
+
+enum Command {
+    Run,
+    Bench { iterations: usize },
+}
+
+struct Config {
+    command: Command,
+    part: Option<Part>,
+    input: Option<Input>,
+    example: bool,
+    params: Params,
+    format: Format,
+    answers: Option<Answers>,
+}
+
+fn default_input(day: u8) -> PathBuf {
+    PathBuf::from(format!("day-{:02}/input.txt", day))
+}
+
+// `--<name> <value>` for each extra parameter the day takes
+fn day_params<S: Solution>(args: &mut Args, example: bool) -> Result<Params, AocError> {
+    let mut params = Params::new(S::PARAMS, example);
+    for param in S::PARAMS {
+        if let Some(value) = args.option::<String>(&format!("--{}", param.name))? {
+            params.set(param.name, value);
+        }
+    }
+    Ok(params)
+}
+
+fn parts<S: Solution>(config: &Config) -> Vec<Part> {
+    match config.part {
+        Some(part) => vec![part],
+        None => S::PARTS.to_vec(),
+    }
+}
+
+// each distinct input with the parts solved from it, only the examples can
+// differ per part
+fn inputs<S: Solution>(config: &Config) -> Result<Vec<(String, Vec<Part>)>, AocError> {
+    if !config.example {
+        let source = match &config.input {
+            Some(input) => input.clone(),
+            None => Input::File(default_input(S::DAY)),
+        };
+        return Ok(vec![(source.read()?, parts::<S>(config))]);
+    }
+
+    let mut inputs: Vec<(String, Vec<Part>)> = vec![];
+    for part in parts::<S>(config) {
+        let Some(example) = S::example(part) else {
+            eprintln!("day {} part {}: skipped, no example", S::DAY, part);
+            continue;
+        };
+        match inputs.iter_mut().find(|(input, _)| input == example) {
+            Some((_, parts)) => parts.push(part),
+            None => inputs.push((example.to_string(), vec![part])),
+        }
+    }
+    Ok(inputs)
+}
+
+fn print_warnings<S: Solution>(solution: &S) {
+    for warning in solution.warnings() {
+        warn!("{}", warning);
+    }
+}
+
+// returns how many answers didn't match the answers file
+fn run_day<S: Solution>(config: &Config) -> Result<usize, AocError> {
+    let mut failed = 0;
+
+    for (input, parts) in inputs::<S>(config)? {
+        let solution = S::parse(&input, &config.params)?;
+        print_warnings(&solution);
+
+        for part in parts {
+            let start = Instant::now();
+            let answer = solution.solve(part)?;
+            let elapsed = start.elapsed();
+
+            let status = config
+                .answers
+                .as_ref()
+                .map(|answers| answers.check(S::DAY, part, &answer));
+            if let Some(Status::Fail(_)) = status {
+                failed += 1;
+            }
+
+            let record = Record {
+                day: S::DAY,
+                part,
+                answer: &answer,
+                elapsed,
+                status: status.as_ref(),
+            };
+            println!("{}", record.format(config.format));
+        }
+    }
+    Ok(failed)
+}
+
+fn bench_day<S: Solution>(config: &Config, iterations: usize) -> Result<usize, AocError> {
+    let report = |stage, stats| {
+        let record = BenchRecord {
+            day: S::DAY,
+            stage,
+            iterations,
+            stats,
+        };
+        println!("{}", record.format(config.format));
+    };
+
+    for (input, parts) in inputs::<S>(config)? {
+        let (stats, solution) = bench::measure(iterations, || S::parse(&input, &config.params))?;
+        print_warnings(&solution);
+        report(Stage::Parse, stats);
+
+        for part in parts {
+            let (stats, _) = bench::measure(iterations, || solution.solve(part))?;
+            report(Stage::Solve(part), stats);
+        }
+    }
+    Ok(0)
+}
+
+fn run_command<S: Solution>(config: &Config) -> Result<usize, AocError> {
+    match config.command {
+        Command::Run => run_day::<S>(config),
+        Command::Bench { iterations } => bench_day::<S>(config, iterations),
+    }
+}
+
+fn main() -> Result<(), AocError> {
+    let mut args = Args::from_env(USAGE);
+    set_verbosity(args.verbosity());
+
+    let day = args.option::<u8>("--day")?;
+    let part = args.option::<Part>("--part")?;
+    let input = args.option::<String>("--input")?;
+    let example = args.flag("--example");
+    let format = args.option::<Format>("--format")?.unwrap_or(Format::Text);
+    let iterations = args.option::<usize>("--iterations")?;
+    let answers = args.option::<PathBuf>("--answers")?;
+
+    if day.is_some_and(|day| !(1..=25).contains(&day)) {
+        return Err(args.error("day must be between 1 and 25".to_string()));
+    }
+    if input.is_some() && example {
+        return Err(args.error("--input and --example can't be combined".to_string()));
+    }
+    if answers.is_some() && example {
+        return Err(args.error("--answers and --example can't be combined".to_string()));
+    }
+    if input.is_some() && day.is_none() {
+        return Err(args.error("--input requires --day".to_string()));
+    }
+
+    let params = match day {
+        Some(day) => with_solution!(day, S => day_params::<S>(&mut args, example))?,
+        None => Params::new(&[], example),
+    };
+
+    let command = match args.positional::<String>("command")?.as_str() {
+        "run" if iterations.is_some() => {
+            return Err(args.error("--iterations is only valid for bench".to_string()));
+        }
+        "run" => Command::Run,
+        "bench" => match iterations.unwrap_or(DEFAULT_ITERATIONS) {
+            0 => return Err(args.error("--iterations must be at least 1".to_string())),
+            iterations => Command::Bench { iterations },
+        },
+        command => return Err(args.error(format!("Unknown command '{}'", command))),
+    };
+    args.finish()?;
+
+    // the answers are for our own inputs, so the examples are never checked
+    let answers = match answers {
+        Some(path) => Some(Answers::load(&path)?),
+        None if !example && Path::new(DEFAULT_ANSWERS).exists() => {
+            Some(Answers::load(Path::new(DEFAULT_ANSWERS))?)
+        }
+        None => None,
+    };
+
+    let mut config = Config {
+        command,
+        part,
+        input: input.map(|arg| Input::from_arg(&arg)),
+        example,
+        params,
+        format,
+        answers,
+    };
+
+    let (failed, errors) = match day {
+        Some(day) => (with_solution!(day, S => run_command::<S>(&config))?, 0),
+        None => run_all(&mut config),
+    };
+
+    if errors > 0 {
+        eprintln!("days that returned an error: {}", errors);
+    }
+    if failed > 0 {
+        eprintln!("answers not matching the answers file: {}", failed);
+    }
+    if failed > 0 || errors > 0 {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+// returns how many answers didn't match the answers file, and how many days
+// returned an error
+fn run_all(config: &mut Config) -> (usize, usize) {
+    let mut failed = 0;
+    let mut errors = 0;
+
+    for day in 1..=25 {
+        if !config.example && !default_input(day).exists() {
+            eprintln!(
+                "day {}: skipped, {} not found",
+                day,
+                default_input(day).display()
+            );
+            continue;
+        }
+
+        with_solution!(day, S => {
+            if config.part.is_some_and(|part| !S::PARTS.contains(&part)) {
+                continue;
+            }
+            config.params = Params::new(S::PARAMS, config.example);
+            match run_command::<S>(config) {
+                Ok(n) => failed += n,
+                Err(e) => {
+                    eprintln!("day {}: {}", day, e);
+                    errors += 1;
+                }
+            }
+        });
+    }
+
+    (failed, errors)
+}
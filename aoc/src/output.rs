@@ -0,0 +1,199 @@
+use std::{fmt::Write, str::FromStr, time::Duration};
+
+use aoc_common::{Answer, Part};
+
+use crate::{answers::Status, bench::Stats};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err("format must be text or json".to_string()),
+        }
+    }
+}
+
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: Part,
+    pub answer: &'a Answer,
+    pub elapsed: Duration,
+    pub status: Option<&'a Status>,
+}
+
+impl Record<'_> {
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.text(),
+            Format::Json => self.json(),
+        }
+    }
+
+    fn text(&self) -> String {
+        let line = format!("day {} part {}: {}", self.day, self.part, self.answer);
+        match self.status {
+            Some(Status::Pass) => format!("{} PASS", line),
+            Some(Status::Fail(expected)) => format!("{} FAIL (expected {})", line, expected),
+            Some(Status::Unknown) => format!("{} UNKNOWN", line),
+            None => line,
+        }
+    }
+
+    // one object per line, e.g. {"day":1,"part":2,"answer":31,"elapsed_ms":0.012}
+    // with an answers file, also "status" and, on failure, "expected"
+    fn json(&self) -> String {
+        let mut json = format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ms\":{:.3}",
+            self.day,
+            self.part,
+            json_answer(self.answer),
+            millis(self.elapsed)
+        );
+
+        match self.status {
+            Some(Status::Pass) => json.push_str(",\"status\":\"pass\""),
+            Some(Status::Fail(expected)) => write!(
+                json,
+                ",\"status\":\"fail\",\"expected\":{}",
+                json_answer(expected)
+            )
+            .unwrap(),
+            Some(Status::Unknown) => json.push_str(",\"status\":\"unknown\""),
+            None => {}
+        }
+
+        json.push('}');
+        json
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+pub struct BenchRecord {
+    pub day: u8,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+impl BenchRecord {
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.text(),
+            Format::Json => self.json(),
+        }
+    }
+
+    fn text(&self) -> String {
+        let stage = match self.stage {
+            Stage::Parse => "parse".to_string(),
+            Stage::Solve(part) => format!("part {}", part),
+        };
+
+        format!(
+            "day {} {}: min {:.3}ms, median {:.3}ms, max {:.3}ms ({} iterations)",
+            self.day,
+            stage,
+            millis(self.stats.min),
+            millis(self.stats.median),
+            millis(self.stats.max),
+            self.iterations
+        )
+    }
+
+    fn json(&self) -> String {
+        let stage = match self.stage {
+            Stage::Parse => "parse".to_string(),
+            Stage::Solve(part) => format!("part{}", part),
+        };
+
+        format!(
+            "{{\"day\":{},\"stage\":\"{}\",\"iterations\":{},\"min_ms\":{:.3},\"median_ms\":{:.3},\"max_ms\":{:.3}}}",
+            self.day,
+            stage,
+            self.iterations,
+            millis(self.stats.min),
+            millis(self.stats.median),
+            millis(self.stats.max)
+        )
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Int(n) => n.to_string(),
+        Answer::Text(s) => json_string(s),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for ch in s.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            ch if ch.is_control() => write!(json, "\\u{:04x}", ch as u32).unwrap(),
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_record() {
+        let answer = Answer::Text("a\"b\\c".to_string());
+        let record = Record {
+            day: 17,
+            part: Part::One,
+            answer: &answer,
+            elapsed: Duration::from_micros(1500),
+            status: None,
+        };
+        assert_eq!(
+            record.format(Format::Json),
+            r#"{"day":17,"part":1,"answer":"a\"b\\c","elapsed_ms":1.500}"#
+        );
+    }
+
+    #[test]
+    fn json_record_with_status() {
+        let expected = Answer::Int(12);
+        let status = Status::Fail(expected);
+        let record = Record {
+            day: 14,
+            part: Part::One,
+            answer: &Answer::Int(11),
+            elapsed: Duration::ZERO,
+            status: Some(&status),
+        };
+        assert_eq!(
+            record.format(Format::Json),
+            r#"{"day":14,"part":1,"answer":11,"elapsed_ms":0.000,"status":"fail","expected":12}"#
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    io,
+};
+
+use aoc_common::{Answer, AocError, Params, Solution};
+
+fn parse_pair(line: &str) -> Result<(i32, i32), String> {
+    let mut loc = line.split_whitespace();
+    let mut next = |side: &str| {
+        let id = loc.next().ok_or(format!("missing {} location ID", side))?;
+        id.parse::<i32>()
+            .map_err(|_| format!("invalid {} location ID '{}'", side, id))
+    };
+
+    let pair = (next("left")?, next("right")?);
+    match loc.next() {
+        Some(extra) => Err(format!("unexpected '{}' after the location IDs", extra)),
+        None => Ok(pair),
+    }
+}
+
+/// Reads the pairs line by line, so a large file is never held in memory as a whole.
+/// `source` names the input in read errors.
+pub fn get_pairs(
+    source: &str,
+    lines: impl Iterator<Item = io::Result<String>>,
+) -> Result<Vec<(i32, i32)>, AocError> {
+    lines
+        .enumerate()
+        .map(|(i, line)| {
+            let line = line.map_err(|error| AocError::Io {
+                source: source.to_string(),
+                error: io::Error::new(error.kind(), format!("line {}: {}", i + 1, error)),
+            })?;
+            parse_pair(&line).map_err(|e| AocError::Parse(format!("line {}: {}", i + 1, e)))
+        })
+        .collect()
+}
+
+fn sorted_lists(pairs: &[(i32, i32)]) -> (Vec<i32>, Vec<i32>) {
+    let (mut left, mut right): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+    left.sort_unstable();
+    right.sort_unstable();
+    (left, right)
+}
+
+// differences between the n-th smallest IDs of each list
+fn sorted_diffs(pairs: &[(i32, i32)]) -> Vec<i64> {
+    let (left, right) = sorted_lists(pairs);
+    left.iter()
+        .zip(&right)
+        .map(|(&l, &r)| (i64::from(l) - i64::from(r)).abs())
+        .collect()
+}
+
+fn counts(ids: impl Iterator<Item = i32>) -> HashMap<i32, usize> {
+    let mut counts = HashMap::new();
+    for id in ids {
+        *counts.entry(id).or_insert(0) += 1;
+    }
+    counts
+}
+
+pub fn distance(pairs: &[(i32, i32)]) -> i64 {
+    sorted_diffs(pairs).iter().sum()
+}
+
+pub fn similarity(pairs: &[(i32, i32)]) -> i64 {
+    let right = counts(pairs.iter().map(|&(_, r)| r));
+
+    pairs
+        .iter()
+        .map(|(l, _)| i64::from(*l) * *right.get(l).unwrap_or(&0) as i64)
+        .sum()
+}
+
+/// Distinct IDs found only in the left list and only in the right list.
+pub fn one_sided(pairs: &[(i32, i32)]) -> (usize, usize) {
+    let frequencies = frequencies(pairs);
+    let left_only = frequencies.values().filter(|&&(_, r)| r == 0).count();
+    let right_only = frequencies.values().filter(|&&(l, _)| l == 0).count();
+    (left_only, right_only)
+}
+
+/// Jaccard similarity of the lists as multisets, 1 for two empty lists.
+pub fn jaccard(pairs: &[(i32, i32)]) -> f64 {
+    let (shared, total) = frequencies(pairs)
+        .values()
+        .fold((0, 0), |(shared, total), &(l, r)| {
+            (shared + l.min(r), total + l.max(r))
+        });
+
+    if total == 0 {
+        1.0
+    } else {
+        shared as f64 / total as f64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiffStats {
+    pub median: f64,
+    pub mean: f64,
+}
+
+/// Median and mean of the differences that make up the distance.
+pub fn diff_stats(pairs: &[(i32, i32)]) -> Option<DiffStats> {
+    let mut diffs = sorted_diffs(pairs);
+    if diffs.is_empty() {
+        return None;
+    }
+    diffs.sort_unstable();
+
+    let mid = diffs.len() / 2;
+    let median = if diffs.len().is_multiple_of(2) {
+        (diffs[mid - 1] + diffs[mid]) as f64 / 2.0
+    } else {
+        diffs[mid] as f64
+    };
+    let mean = diffs.iter().sum::<i64>() as f64 / diffs.len() as f64;
+
+    Some(DiffStats { median, mean })
+}
+
+/// How often each ID occurs in the left and in the right list.
+pub fn frequencies(pairs: &[(i32, i32)]) -> BTreeMap<i32, (usize, usize)> {
+    let mut frequencies = BTreeMap::new();
+    for (id, n) in counts(pairs.iter().map(|&(l, _)| l)) {
+        frequencies.entry(id).or_insert((0, 0)).0 = n;
+    }
+    for (id, n) in counts(pairs.iter().map(|&(_, r)| r)) {
+        frequencies.entry(id).or_insert((0, 0)).1 = n;
+    }
+    frequencies
+}
+
+pub struct Day01 {
+    pairs: Vec<(i32, i32)>,
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        let lines = input.lines().map(|line| Ok(line.to_string()));
+        Ok(Self {
+            pairs: get_pairs("<input>", lines)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(distance(&self.pairs).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(similarity(&self.pairs).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> Day01 {
+        Day01::parse(EXAMPLE, &Params::new(Day01::PARAMS, true)).unwrap()
+    }
+
+    #[test]
+    fn distance_example() {
+        assert_eq!(example().part1().unwrap(), Answer::Int(11));
+    }
+
+    #[test]
+    fn similarity_example() {
+        assert_eq!(example().part2().unwrap(), Answer::Int(31));
+    }
+
+    #[test]
+    fn extra_metrics_example() {
+        let pairs = example().pairs;
+        assert_eq!(one_sided(&pairs), (2, 2));
+        assert_eq!(jaccard(&pairs), 0.5);
+        assert_eq!(
+            diff_stats(&pairs),
+            Some(DiffStats {
+                median: 1.5,
+                mean: 11.0 / 6.0
+            })
+        );
+        assert_eq!(frequencies(&pairs)[&3], (3, 3));
+        assert_eq!(frequencies(&pairs)[&9], (0, 1));
+        assert_eq!(diff_stats(&[]), None);
+    }
+
+    #[test]
+    fn large_ids_do_not_overflow() {
+        let pairs = [
+            (i32::MAX, i32::MIN),
+            (i32::MAX, i32::MAX),
+            (i32::MAX, i32::MAX),
+        ];
+        assert_eq!(distance(&pairs), 2 * i64::from(i32::MAX) + 1);
+        assert_eq!(similarity(&pairs), 6 * i64::from(i32::MAX));
+    }
+
+    #[test]
+    fn malformed_lines_are_reported() {
+        let lines = |input: &'static str| input.lines().map(|l| Ok(l.to_string()));
+        let err = get_pairs("<input>", lines("1   2\n3 x")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "ParseError: line 2: invalid right location ID 'x'"
+        );
+        let err = get_pairs("<input>", lines("1   2\n\n")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "ParseError: line 2: missing left location ID"
+        );
+    }
+
+    #[test]
+    fn read_errors_name_the_input() {
+        let lines = [
+            Ok("1   2".to_string()),
+            Err(io::Error::new(io::ErrorKind::InvalidData, "bad bytes")),
+        ];
+        let err = get_pairs("input.txt", lines.into_iter()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to read 'input.txt': line 2: bad bytes"
+        );
+    }
+}
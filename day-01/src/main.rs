@@ -1,81 +1,47 @@
-use std::{
-    cmp::Reverse,
-    collections::{BinaryHeap, HashMap},
-    fs::File,
-    io::{BufRead, BufReader, Result},
-    path::Path,
-};
+use aoc_common::{AocError, Args, Solution};
+use day_01::{diff_stats, distance, frequencies, get_pairs, jaccard, one_sided, similarity, Day01};
 
-fn read_lines<P>(filename: P) -> Result<impl Iterator<Item = Result<String>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(BufReader::new(file).lines())
-}
-
-fn get_pairs(lines: impl Iterator<Item = Result<String>>) -> impl Iterator<Item = (i32, i32)> {
-    lines.flatten().map(|line| {
-        let mut loc = line.split_whitespace();
-        let left = loc.next().unwrap().parse::<i32>().unwrap();
-        let right = loc.next().unwrap().parse::<i32>().unwrap();
-        (left, right)
-    })
-}
-
-fn calc_distance(filename: &str) {
-    let mut left = BinaryHeap::new();
-    let mut right = BinaryHeap::new();
-
-    get_pairs(read_lines(filename).unwrap()).for_each(|(l, r)| {
-        left.push(Reverse(l));
-        right.push(Reverse(r));
-    });
-
-    println!("{0}", distance(left, right));
-}
-
-fn distance(mut left: BinaryHeap<Reverse<i32>>, mut right: BinaryHeap<Reverse<i32>>) -> i32 {
-    let mut res = 0;
-    while !left.is_empty() {
-        let l = left.pop().unwrap().0;
-        let r = right.pop().unwrap().0;
-        res += (l - r).abs();
-    }
-    res
-}
+const USAGE: &str =
+    "[--operation <parts|distance|similarity|one-sided|jaccard|diff-stats|frequencies>] \
+     <filename|-|--example>";
 
-fn calc_similarity(filename: &str) {
-    let mut left = Vec::new();
-    let mut right = HashMap::new();
+fn main() -> Result<(), AocError> {
+    let mut args = Args::from_env(USAGE);
+    let operation = args
+        .option::<String>("--operation")?
+        .unwrap_or_else(|| "parts".to_string());
+    let input = args.input(Day01::EXAMPLE)?;
+    args.finish()?;
 
-    get_pairs(read_lines(filename).unwrap()).for_each(|(l, r)| {
-        left.push(l);
-
-        right.insert(r, *right.get(&r).unwrap_or(&0) + 1);
-    });
-
-    println!("{0}", similarity(left, right));
-}
-
-fn similarity(left: Vec<i32>, right: HashMap<i32, i32>) -> i32 {
-    let mut res = 0;
-    for loc in left {
-        let r: i32 = *right.get(&loc).unwrap_or(&0);
-        res += loc * r;
-    }
-    res
-}
-
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-
-    let operation = &args[1];
-    let filename = &args[2];
+    let pairs = get_pairs(&input.name(), input.lines()?)?;
 
     match operation.as_str() {
-        "distance" => calc_distance(filename.as_str()),
-        "similarity" => calc_similarity(filename.as_str()),
-        _ => panic!("Unknown operation"),
+        "parts" => {
+            println!("distance: {}", distance(&pairs));
+            println!("similarity: {}", similarity(&pairs));
+        }
+        "distance" => println!("{}", distance(&pairs)),
+        "similarity" => println!("{}", similarity(&pairs)),
+        "one-sided" => {
+            let (left, right) = one_sided(&pairs);
+            println!("left only: {}", left);
+            println!("right only: {}", right);
+        }
+        "jaccard" => println!("{:.4}", jaccard(&pairs)),
+        "diff-stats" => match diff_stats(&pairs) {
+            Some(stats) => {
+                println!("median: {}", stats.median);
+                println!("mean: {:.4}", stats.mean);
+            }
+            None => println!("no pairs"),
+        },
+        "frequencies" => {
+            for (id, (left, right)) in frequencies(&pairs) {
+                println!("{}: left {}, right {}", id, left, right);
+            }
+        }
+        _ => return Err(args.error(format!("Unknown operation '{}'", operation))),
     }
+
+    Ok(())
 }
@@ -0,0 +1,431 @@
+use std::io;
+
+use aoc_common::{Answer, AocError, Param, Params, Solution};
+
+pub fn get_reports(
+    lines: impl Iterator<Item = io::Result<String>>,
+) -> impl Iterator<Item = Vec<i32>> {
+    lines.filter_map(|line| {
+        line.ok().map(|l| {
+            l.split_whitespace()
+                .filter_map(|level| level.parse::<i32>().ok())
+                .collect()
+        })
+    })
+}
+
+/// Levels the Problem Dampener may remove, the default for part 2.
+pub const DAMPENER_TOLERANCE: usize = 1;
+
+/// Allowed step size between neighbouring levels, and how many levels may be removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyRules {
+    pub min_step: i32,
+    pub max_step: i32,
+    pub tolerance: usize,
+}
+
+// the part 1 rules, without the dampener
+impl Default for SafetyRules {
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            tolerance: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    Increasing,
+    Decreasing,
+    /// Safe only once the dampener removes levels.
+    Dampened,
+    Unsafe,
+}
+
+/// How many reports fall into each [`Classification`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Breakdown {
+    pub increasing: usize,
+    pub decreasing: usize,
+    pub dampened: usize,
+    pub unsafe_reports: usize,
+}
+
+impl Breakdown {
+    pub fn new<'a>(rules: &SafetyRules, reports: impl IntoIterator<Item = &'a Vec<i32>>) -> Self {
+        let mut breakdown = Self::default();
+        for report in reports {
+            match rules.classify(report) {
+                Classification::Increasing => breakdown.increasing += 1,
+                Classification::Decreasing => breakdown.decreasing += 1,
+                Classification::Dampened => breakdown.dampened += 1,
+                Classification::Unsafe => breakdown.unsafe_reports += 1,
+            }
+        }
+        breakdown
+    }
+
+    /// Safe without removing any level, part 1.
+    pub fn strict(&self) -> usize {
+        self.increasing + self.decreasing
+    }
+
+    /// Safe with the dampener, part 2.
+    pub fn tolerant(&self) -> usize {
+        self.strict() + self.dampened
+    }
+}
+
+/// A window `index..=index + 1` whose step breaks the rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub index: usize,
+    pub step: i32,
+}
+
+/// Why a report is unsafe, judged in the direction that needs the fewest removals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub increasing: bool,
+    pub violations: Vec<Violation>,
+    /// Indices of the levels to remove, if that is possible within the tolerance.
+    pub removals: Option<Vec<usize>>,
+}
+
+impl SafetyRules {
+    pub fn with_tolerance(self, tolerance: usize) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    fn is_safe_step(&self, step: i32, increasing: bool) -> bool {
+        let step = if increasing { step } else { -step };
+        (self.min_step..=self.max_step).contains(&step)
+    }
+
+    fn violations(&self, report: &[i32], increasing: bool) -> Vec<Violation> {
+        report
+            .windows(2)
+            .enumerate()
+            .map(|(index, w)| Violation {
+                index,
+                step: w[1] - w[0],
+            })
+            .filter(|v| !self.is_safe_step(v.step, increasing))
+            .collect()
+    }
+
+    // indices of the longest subsequence whose neighbouring steps are all safe
+    fn longest_safe_subsequence(&self, report: &[i32], increasing: bool) -> Vec<usize> {
+        let mut len = vec![1; report.len()];
+        let mut prev = vec![None; report.len()];
+
+        for i in 0..report.len() {
+            for j in 0..i {
+                if len[j] + 1 > len[i] && self.is_safe_step(report[i] - report[j], increasing) {
+                    len[i] = len[j] + 1;
+                    prev[i] = Some(j);
+                }
+            }
+        }
+
+        let mut kept = vec![];
+        let mut last = (0..report.len()).max_by_key(|&i| (len[i], std::cmp::Reverse(i)));
+        while let Some(i) = last {
+            kept.push(i);
+            last = prev[i];
+        }
+        kept.reverse();
+        kept
+    }
+
+    fn removals(&self, report: &[i32], increasing: bool) -> Vec<usize> {
+        let kept = self.longest_safe_subsequence(report, increasing);
+        (0..report.len()).filter(|i| !kept.contains(i)).collect()
+    }
+
+    fn is_safe_skipping(&self, report: &[i32], skip: Option<usize>, increasing: bool) -> bool {
+        let mut levels = report
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| Some(i) != skip)
+            .map(|(_, &level)| level);
+
+        let Some(mut prev) = levels.next() else {
+            return true;
+        };
+        levels.all(|level| {
+            let safe = self.is_safe_step(level - prev, increasing);
+            prev = level;
+            safe
+        })
+    }
+
+    // The first bad window has to lose one of its two levels, removing
+    // anything before it leaves that window as it is. O(n), no allocations.
+    fn is_safe_with_one_removal(&self, report: &[i32], increasing: bool) -> bool {
+        match report
+            .windows(2)
+            .position(|w| !self.is_safe_step(w[1] - w[0], increasing))
+        {
+            None => true,
+            Some(i) => {
+                self.is_safe_skipping(report, Some(i), increasing)
+                    || self.is_safe_skipping(report, Some(i + 1), increasing)
+            }
+        }
+    }
+
+    pub fn is_safe(&self, report: &[i32]) -> bool {
+        [true, false]
+            .into_iter()
+            .any(|increasing| match self.tolerance {
+                0 => self.is_safe_skipping(report, None, increasing),
+                1 => self.is_safe_with_one_removal(report, increasing),
+                k => self.removals(report, increasing).len() <= k,
+            })
+    }
+
+    pub fn classify(&self, report: &[i32]) -> Classification {
+        if self.is_safe_skipping(report, None, true) {
+            Classification::Increasing
+        } else if self.is_safe_skipping(report, None, false) {
+            Classification::Decreasing
+        } else if self.is_safe(report) {
+            Classification::Dampened
+        } else {
+            Classification::Unsafe
+        }
+    }
+
+    /// `None` for a report that is safe as it is.
+    pub fn explain(&self, report: &[i32]) -> Option<Explanation> {
+        let (increasing, violations, removals) = [true, false]
+            .into_iter()
+            .map(|increasing| {
+                (
+                    increasing,
+                    self.violations(report, increasing),
+                    self.removals(report, increasing),
+                )
+            })
+            .min_by_key(|(_, violations, removals)| (removals.len(), violations.len()))?;
+
+        if violations.is_empty() {
+            return None;
+        }
+
+        Some(Explanation {
+            increasing,
+            violations,
+            removals: (removals.len() <= self.tolerance).then_some(removals),
+        })
+    }
+}
+
+pub fn is_safe_report(report: &[i32]) -> bool {
+    SafetyRules::default().is_safe(report)
+}
+
+pub fn is_safe_with_tolerance(report: &[i32]) -> bool {
+    SafetyRules::default()
+        .with_tolerance(DAMPENER_TOLERANCE)
+        .is_safe(report)
+}
+
+pub fn count_safe_reports(reports: impl Iterator<Item = Vec<i32>>) -> usize {
+    reports.filter(|r| is_safe_with_tolerance(r)).count()
+}
+
+pub struct Day02 {
+    reports: Vec<Vec<i32>>,
+    rules: SafetyRules,
+}
+
+impl Day02 {
+    fn count_safe(&self, rules: SafetyRules) -> usize {
+        self.reports.iter().filter(|r| rules.is_safe(r)).count()
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "min-step",
+            default: "1",
+            example: "1",
+        },
+        Param {
+            name: "max-step",
+            default: "3",
+            example: "3",
+        },
+        Param {
+            name: "tolerance",
+            default: "1",
+            example: "1",
+        },
+    ];
+
+    fn parse(input: &str, params: &Params) -> Result<Self, AocError> {
+        let lines = input.lines().map(|line| Ok(line.to_string()));
+        Ok(Self {
+            reports: get_reports(lines).collect(),
+            rules: SafetyRules {
+                min_step: params.get("min-step")?,
+                max_step: params.get("max-step")?,
+                tolerance: params.get("tolerance")?,
+            },
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(self.count_safe(self.rules.with_tolerance(0)).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(self.count_safe(self.rules).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::rng::XorShift;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example_reports() -> Vec<Vec<i32>> {
+        get_reports(EXAMPLE.lines().map(|line| Ok(line.to_string()))).collect()
+    }
+
+    #[test]
+    fn default_params_match_default_rules() {
+        for example in [false, true] {
+            let day = Day02::parse(EXAMPLE, &Params::new(Day02::PARAMS, example)).unwrap();
+            assert_eq!(
+                day.rules,
+                SafetyRules::default().with_tolerance(DAMPENER_TOLERANCE)
+            );
+        }
+    }
+
+    // the original check, trying every single removal on a copy
+    fn is_safe_with_tolerance_brute_force(report: &[i32]) -> bool {
+        if is_safe_report(report) {
+            return true;
+        }
+
+        for i in 0..report.len() {
+            let mut modified = report.to_vec();
+            modified.remove(i);
+            if is_safe_report(&modified) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn random_reports(count: usize) -> Vec<Vec<i32>> {
+        let mut rng = XorShift::new(0x2545_f491_4f6c_dd1d);
+        let mut next = |bound: u64| rng.below(bound);
+
+        (0..count)
+            .map(|_| {
+                let len = next(9) as usize;
+                let mut level = next(20) as i32;
+                (0..len)
+                    .map(|_| {
+                        level += next(11) as i32 - 5;
+                        level
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn safe_reports_example() {
+        let safe = example_reports()
+            .iter()
+            .filter(|r| is_safe_report(r))
+            .count();
+        assert_eq!(safe, 2);
+    }
+
+    #[test]
+    fn safe_reports_with_tolerance_example() {
+        assert_eq!(count_safe_reports(example_reports().into_iter()), 4);
+    }
+
+    #[test]
+    fn dampener_matches_brute_force() {
+        let dampened = SafetyRules::default().with_tolerance(1);
+        for report in random_reports(20_000) {
+            let expected = is_safe_with_tolerance_brute_force(&report);
+            assert_eq!(is_safe_with_tolerance(&report), expected, "{:?}", report);
+            assert_eq!(
+                dampened.removals(&report, true).len() <= 1
+                    || dampened.removals(&report, false).len() <= 1,
+                expected,
+                "{:?}",
+                report
+            );
+        }
+    }
+
+    #[test]
+    fn breakdown_example() {
+        let rules = SafetyRules::default().with_tolerance(1);
+        let breakdown = Breakdown::new(&rules, &example_reports());
+        assert_eq!(
+            breakdown,
+            Breakdown {
+                increasing: 1,
+                decreasing: 1,
+                dampened: 2,
+                unsafe_reports: 2,
+            }
+        );
+        assert_eq!((breakdown.strict(), breakdown.tolerant()), (2, 4));
+    }
+
+    #[test]
+    fn configurable_rules() {
+        let rules = SafetyRules::default();
+        assert!(!rules.is_safe(&[1, 5, 6]));
+        assert!(rules.with_tolerance(1).is_safe(&[1, 5, 6]));
+        assert!(SafetyRules {
+            max_step: 4,
+            ..rules
+        }
+        .is_safe(&[1, 5, 6]));
+        assert!(!rules.with_tolerance(1).is_safe(&[1, 9, 2, 9, 3]));
+        assert!(rules.with_tolerance(2).is_safe(&[1, 9, 2, 9, 3]));
+
+        let tolerant = example_reports()
+            .iter()
+            .filter(|r| rules.with_tolerance(1).is_safe(r))
+            .count();
+        assert_eq!(tolerant, 4);
+    }
+
+    #[test]
+    fn explain_unsafe_report() {
+        let rules = SafetyRules::default().with_tolerance(1);
+        assert_eq!(rules.explain(&[7, 6, 4, 2, 1]), None);
+        assert_eq!(
+            rules.explain(&[1, 3, 2, 4, 5]),
+            Some(Explanation {
+                increasing: true,
+                violations: vec![Violation { index: 1, step: -1 }],
+                removals: Some(vec![2]),
+            })
+        );
+        assert_eq!(rules.explain(&[1, 2, 7, 8, 9]).unwrap().removals, None);
+    }
+}
@@ -1,89 +1,74 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    path::Path,
-};
+use aoc_common::{AocError, Args, Solution};
+use day_02::{get_reports, Breakdown, Day02, SafetyRules, DAMPENER_TOLERANCE};
 
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(BufReader::new(file).lines())
-}
-
-fn get_reports(lines: io::Lines<BufReader<File>>) -> impl Iterator<Item = Vec<i32>> {
-    lines.filter_map(|line| {
-        line.ok().map(|l| {
-            l.split_whitespace()
-                .filter_map(|level| level.parse::<i32>().ok())
-                .collect()
-        })
-    })
-}
-
-fn is_safe_report(report: &[i32]) -> bool {
-    is_decreasing_report(report) || is_increasing_report(report)
-}
-
-fn is_safe_increasing(diff: &i32) -> bool {
-    (1..=3).contains(diff)
-}
-
-fn is_increasing_report(report: &[i32]) -> bool {
-    report
-        .windows(2)
-        .all(|w| is_safe_increasing(&(w[1] - w[0])))
-}
+const USAGE: &str = "[--min-step <n>] [--max-step <n>] [--tolerance <k>] [--explain] \
+     <filename|-|--example>";
 
-fn is_safe_decreasing(diff: &i32) -> bool {
-    (-3..=-1).contains(diff)
-}
+fn explain(reports: &[Vec<i32>], rules: &SafetyRules) {
+    for (n, report) in reports.iter().enumerate() {
+        let Some(explanation) = rules.explain(report) else {
+            continue;
+        };
 
-fn is_decreasing_report(report: &[i32]) -> bool {
-    report
-        .windows(2)
-        .all(|w| is_safe_decreasing(&(w[1] - w[0])))
-}
+        let levels: Vec<String> = report.iter().map(|l| l.to_string()).collect();
+        let direction = if explanation.increasing {
+            "increasing"
+        } else {
+            "decreasing"
+        };
+        println!("report {}: {} ({})", n + 1, levels.join(" "), direction);
 
-fn is_safe_with_tolerance(report: &[i32]) -> bool {
-    if is_safe_report(report) {
-        return true;
-    }
+        for v in &explanation.violations {
+            println!(
+                "  levels {}-{}: {} -> {} steps by {}",
+                v.index + 1,
+                v.index + 2,
+                report[v.index],
+                report[v.index + 1],
+                v.step
+            );
+        }
 
-    for i in 0..report.len() {
-        let mut modified = report.to_vec();
-        modified.remove(i);
-        if is_safe_report(&modified) {
-            return true;
+        match explanation.removals {
+            Some(removals) => {
+                let removals: Vec<String> = removals
+                    .iter()
+                    .map(|&i| format!("level {} ({})", i + 1, report[i]))
+                    .collect();
+                println!("  safe after removing {}", removals.join(", "));
+            }
+            None => println!(
+                "  unsafe even after removing up to {} level(s)",
+                rules.tolerance
+            ),
         }
     }
-    false
 }
 
-fn count_safe_reports(reports: impl Iterator<Item = Vec<i32>>) -> usize {
-    reports.filter(|r| is_safe_with_tolerance(r)).count()
-}
+fn main() -> Result<(), AocError> {
+    let mut args = Args::from_env(USAGE);
+    let defaults = SafetyRules::default();
+    let rules = SafetyRules {
+        min_step: args.option("--min-step")?.unwrap_or(defaults.min_step),
+        max_step: args.option("--max-step")?.unwrap_or(defaults.max_step),
+        tolerance: args.option("--tolerance")?.unwrap_or(DAMPENER_TOLERANCE),
+    };
+    let explain_reports = args.flag("--explain");
+    let source = args.input(Day02::EXAMPLE)?;
+    args.finish()?;
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
+    let reports: Vec<Vec<i32>> = get_reports(source.lines()?).collect();
+    let breakdown = Breakdown::new(&rules, &reports);
+    println!("part 1: {}", breakdown.strict());
+    println!("part 2: {}", breakdown.tolerant());
+    println!("increasing: {}", breakdown.increasing);
+    println!("decreasing: {}", breakdown.decreasing);
+    println!("dampener only: {}", breakdown.dampened);
+    println!("unsafe: {}", breakdown.unsafe_reports);
 
-    if args.len() < 2 {
-        eprintln!("Usage: {} <filename>", args[0]);
-        std::process::exit(1);
+    if explain_reports {
+        explain(&reports, &rules);
     }
 
-    let filename = &args[1];
-
-    match read_lines(filename) {
-        Ok(lines) => {
-            let reports = get_reports(lines);
-            let safe = count_safe_reports(reports);
-            println!("{0}", safe);
-        }
-        Err(e) => {
-            eprintln!("Failed to read file '{}': {}", filename, e);
-            std::process::exit(1);
-        }
-    }
+    Ok(())
 }
@@ -0,0 +1,279 @@
+use std::ops::Range;
+
+use aoc_common::{Answer, AocError, Params, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+impl Token {
+    // every instruction the lexer recognises, as name and number of arguments
+    const INSTRUCTIONS: &[(&'static str, usize)] = &[("mul", 2), ("do", 0), ("don't", 0)];
+
+    fn new(name: &str, args: &[i32]) -> Option<Self> {
+        match (name, args) {
+            ("mul", &[a, b]) => Some(Self::Mul(a, b)),
+            ("do", []) => Some(Self::Do),
+            ("don't", []) => Some(Self::Dont),
+            _ => None,
+        }
+    }
+}
+
+/// A token and the bytes of the input it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned {
+    pub token: Token,
+    pub span: Range<usize>,
+}
+
+/// Finds the well-formed instructions in corrupted input, skipping everything else.
+pub struct Lexer<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    // `name(a,b,...)` at `start` with exactly `arity` arguments, returns the end
+    fn instruction(&self, start: usize, name: &str, arity: usize) -> Option<(Token, usize)> {
+        let mut pos = start + name.len();
+        if !self.input[start..].starts_with(name.as_bytes()) || self.input.get(pos) != Some(&b'(') {
+            return None;
+        }
+        pos += 1;
+
+        let mut args = Vec::with_capacity(arity);
+        for i in 0..arity {
+            if i > 0 {
+                if self.input.get(pos) != Some(&b',') {
+                    return None;
+                }
+                pos += 1;
+            }
+            let (arg, end) = self.number(pos)?;
+            args.push(arg);
+            pos = end;
+        }
+
+        if self.input.get(pos) != Some(&b')') {
+            return None;
+        }
+        Token::new(name, &args).map(|token| (token, pos + 1))
+    }
+
+    // 1 to 3 digits, a longer number is not an argument
+    fn number(&self, start: usize) -> Option<(i32, usize)> {
+        let digits = self.input[start..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if !(1..=3).contains(&digits) {
+            return None;
+        }
+
+        let number = self.input[start..start + digits]
+            .iter()
+            .fold(0, |n, b| n * 10 + i32::from(b - b'0'));
+        Some((number, start + digits))
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Spanned;
+
+    fn next(&mut self) -> Option<Spanned> {
+        while self.pos < self.input.len() {
+            let start = self.pos;
+            let found = Token::INSTRUCTIONS
+                .iter()
+                .find_map(|&(name, arity)| self.instruction(start, name, arity));
+
+            match found {
+                Some((token, end)) => {
+                    self.pos = end;
+                    return Some(Spanned {
+                        token,
+                        span: start..end,
+                    });
+                }
+                None => self.pos += 1,
+            }
+        }
+        None
+    }
+}
+
+pub fn tokenize(instructions: &str) -> Vec<Spanned> {
+    Lexer::new(instructions).collect()
+}
+
+/// Runs instructions one at a time, `do()` and `don't()` only count with `conditionals`.
+pub struct Interpreter {
+    conditionals: bool,
+    enabled: bool,
+    sum: i64,
+}
+
+impl Interpreter {
+    pub fn new(conditionals: bool) -> Self {
+        Self {
+            conditionals,
+            enabled: true,
+            sum: 0,
+        }
+    }
+
+    /// Returns whether the instruction took effect.
+    pub fn step(&mut self, token: Token) -> bool {
+        match token {
+            Token::Mul(a, b) if self.enabled || !self.conditionals => {
+                self.sum += i64::from(a) * i64::from(b);
+                true
+            }
+            Token::Mul(..) => false,
+            Token::Do => {
+                self.enabled = true;
+                self.conditionals
+            }
+            Token::Dont => {
+                self.enabled = false;
+                self.conditionals
+            }
+        }
+    }
+
+    pub fn sum(&self) -> i64 {
+        self.sum
+    }
+}
+
+pub fn run(tokens: &[Spanned], conditionals: bool) -> i64 {
+    let mut interpreter = Interpreter::new(conditionals);
+    for spanned in tokens {
+        interpreter.step(spanned.token);
+    }
+    interpreter.sum()
+}
+
+/// Pairs each token with whether it took effect with `do()`/`don't()` honoured.
+pub fn annotate(tokens: &[Spanned]) -> Vec<(&Spanned, bool)> {
+    let mut interpreter = Interpreter::new(true);
+    tokens
+        .iter()
+        .map(|spanned| (spanned, interpreter.step(spanned.token)))
+        .collect()
+}
+
+/// The input with every instruction in brackets, `[!...]` for a disabled one.
+pub fn highlight(instructions: &str, annotated: &[(&Spanned, bool)]) -> String {
+    let mut highlighted = String::with_capacity(instructions.len());
+    let mut pos = 0;
+
+    for (spanned, enabled) in annotated {
+        let Range { start, end } = spanned.span;
+        highlighted.push_str(&instructions[pos..start]);
+        highlighted.push('[');
+        if !enabled {
+            highlighted.push('!');
+        }
+        highlighted.push_str(&instructions[start..end]);
+        highlighted.push(']');
+        pos = end;
+    }
+
+    highlighted.push_str(&instructions[pos..]);
+    highlighted
+}
+
+pub struct Day03 {
+    tokens: Vec<Spanned>,
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        Ok(Self {
+            tokens: tokenize(input),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(run(&self.tokens, false).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(run(&self.tokens, true).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn multiplications_example() {
+        assert_eq!(run(&tokenize(EXAMPLE), false), 161);
+    }
+
+    #[test]
+    fn conditional_multiplications_example() {
+        assert_eq!(run(&tokenize(EXAMPLE), true), 48);
+    }
+
+    #[test]
+    fn highlight_marks_disabled_instructions() {
+        let input = "xmul(2,4)don't()mul(5,5)do()?mul(8,5)";
+        let tokens = tokenize(input);
+        let annotated = annotate(&tokens);
+        assert_eq!(
+            highlight(input, &annotated),
+            "x[mul(2,4)][don't()][!mul(5,5)][do()]?[mul(8,5)]"
+        );
+    }
+
+    #[test]
+    fn tokens_have_spans() {
+        let tokens = tokenize("xmul(2,4)&don't()_do()");
+        assert_eq!(
+            tokens,
+            [
+                Spanned {
+                    token: Token::Mul(2, 4),
+                    span: 1..9
+                },
+                Spanned {
+                    token: Token::Dont,
+                    span: 10..17
+                },
+                Spanned {
+                    token: Token::Do,
+                    span: 18..22
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn arguments_have_one_to_three_digits() {
+        let tokens =
+            |input| -> Vec<Token> { tokenize(input).into_iter().map(|s| s.token).collect() };
+        assert_eq!(tokens("mul(999,1)"), [Token::Mul(999, 1)]);
+        assert_eq!(tokens("mul(1000,1)mul(1,)mul(,1)mul( 1,1)"), []);
+        assert_eq!(tokens("mul(mul(3,4)"), [Token::Mul(3, 4)]);
+        assert_eq!(tokens("do(1)don't"), []);
+    }
+}
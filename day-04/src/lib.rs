@@ -0,0 +1,219 @@
+use aoc_common::{Answer, AocError, Grid, Params, Point, Solution};
+
+/// Accepts CRLF line endings and trailing blank lines, errors name the line
+/// of a blank or ragged row.
+pub fn get_search_matrix(word_search: &str) -> Result<Grid<char>, String> {
+    let mut lines: Vec<&str> = word_search
+        .lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .collect();
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    if lines.is_empty() {
+        return Err("empty word search".to_string());
+    }
+    if let Some(i) = lines.iter().position(|line| line.trim().is_empty()) {
+        return Err(format!("line {}: blank line inside the word search", i + 1));
+    }
+
+    lines.join("\n").parse()
+}
+
+// letters by offset from the top-left corner of the shape, in reading order
+type Shape = Vec<(Point, char)>;
+
+fn normalise(mut cells: Shape) -> Shape {
+    let min_row = cells.iter().map(|(p, _)| p.row).min().unwrap_or(0);
+    let min_col = cells.iter().map(|(p, _)| p.col).min().unwrap_or(0);
+    for (p, _) in cells.iter_mut() {
+        *p -= Point::new(min_row, min_col);
+    }
+    cells
+}
+
+fn same_cells(a: &Shape, b: &Shape) -> bool {
+    let mut a = a.clone();
+    let mut b = b.clone();
+    a.sort();
+    b.sort();
+    a == b
+}
+
+/// Something to look for, in every orientation it can appear in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    variants: Vec<Shape>,
+}
+
+impl Pattern {
+    pub const WILDCARD: char = '.';
+
+    fn new(variants: impl Iterator<Item = Shape>) -> Self {
+        let mut unique: Vec<Shape> = vec![];
+        for shape in variants.filter(|shape| !shape.is_empty()).map(normalise) {
+            if !unique.iter().any(|seen| same_cells(seen, &shape)) {
+                unique.push(shape);
+            }
+        }
+        Self { variants: unique }
+    }
+
+    /// A word in a straight line, in all eight directions.
+    pub fn word(word: &str) -> Self {
+        Self::new(Point::ADJACENT.into_iter().map(|step| {
+            (0..)
+                .zip(word.chars())
+                .map(|(k, ch)| (step * k, ch))
+                .collect()
+        }))
+    }
+
+    /// A 2D template where [`Pattern::WILDCARD`] matches anything, in all
+    /// rotations and reflections.
+    pub fn template(template: &str) -> Self {
+        let cells: Shape = template
+            .lines()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|&(_, ch)| ch != Self::WILDCARD)
+                    .map(move |(col, ch)| (Point::new(row as i32, col as i32), ch))
+            })
+            .collect();
+
+        let reflected: Shape = cells
+            .iter()
+            .map(|&(p, ch)| (Point::new(p.row, -p.col), ch))
+            .collect();
+
+        Self::new([cells, reflected].into_iter().flat_map(|shape| {
+            (0..4).scan(shape, |shape, _| {
+                let current = shape.clone();
+                for (p, _) in shape.iter_mut() {
+                    *p = p.rotate_right();
+                }
+                Some(current)
+            })
+        }))
+    }
+
+    /// Coordinates of the letters of every match, in pattern order.
+    pub fn find(&self, grid: &Grid<char>) -> Vec<Vec<Point>> {
+        let mut matches = vec![];
+
+        for shape in &self.variants {
+            for origin in grid.positions() {
+                if shape
+                    .iter()
+                    .all(|&(offset, ch)| grid.get(origin + offset) == Some(&ch))
+                {
+                    matches.push(shape.iter().map(|&(offset, _)| origin + offset).collect());
+                }
+            }
+        }
+
+        matches.sort();
+        matches
+    }
+
+    pub fn count(&self, grid: &Grid<char>) -> usize {
+        self.find(grid).len()
+    }
+}
+
+const X_MAS: &str = "\
+M.S
+.A.
+M.S";
+
+pub fn count_xmas(search_matrix: &Grid<char>) -> usize {
+    Pattern::word("XMAS").count(search_matrix)
+}
+
+pub fn count_x_mas(search_matrix: &Grid<char>) -> usize {
+    Pattern::template(X_MAS).count(search_matrix)
+}
+
+pub struct Day04 {
+    search_matrix: Grid<char>,
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        Ok(Self {
+            search_matrix: get_search_matrix(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(count_xmas(&self.search_matrix).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(count_x_mas(&self.search_matrix).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn count_xmas_example() {
+        assert_eq!(count_xmas(&get_search_matrix(EXAMPLE).unwrap()), 18);
+    }
+
+    #[test]
+    fn count_x_mas_example() {
+        assert_eq!(count_x_mas(&get_search_matrix(EXAMPLE).unwrap()), 9);
+    }
+
+    #[test]
+    fn input_is_normalised() {
+        let crlf = EXAMPLE.replace('\n', "\r\n") + "\r\n\r\n";
+        assert_eq!(get_search_matrix(&crlf), get_search_matrix(EXAMPLE));
+        assert_eq!(get_search_matrix("AB\r\nCD\r").unwrap().cols(), 2);
+
+        assert_eq!(
+            get_search_matrix("ABC\nABC\nAB\nABC\n"),
+            Err("line 3: expected 3 columns, found 2".to_string())
+        );
+        assert_eq!(
+            get_search_matrix("ABC\n\nABC"),
+            Err("line 2: blank line inside the word search".to_string())
+        );
+        assert_eq!(
+            get_search_matrix("\n \n"),
+            Err("empty word search".to_string())
+        );
+    }
+
+    #[test]
+    fn orientations_are_unique() {
+        assert_eq!(Pattern::word("XMAS").variants.len(), 8);
+        assert_eq!(Pattern::word("ABA").variants.len(), 4);
+        assert_eq!(Pattern::template(X_MAS).variants.len(), 4);
+        assert_eq!(Pattern::template("AB\nC.").variants.len(), 8);
+        assert_eq!(Pattern::template("").variants.len(), 0);
+    }
+
+    #[test]
+    fn matches_have_coordinates() {
+        let grid: Grid<char> = "CAT\n.A.\nTAC".parse().unwrap();
+        assert_eq!(
+            Pattern::word("CAT").find(&grid),
+            [
+                vec![Point::new(0, 0), Point::new(0, 1), Point::new(0, 2)],
+                vec![Point::new(2, 2), Point::new(2, 1), Point::new(2, 0)],
+            ]
+        );
+    }
+}
@@ -0,0 +1,594 @@
+use std::fmt;
+
+use aoc_common::{Answer, AocError, Param, Params, Solution};
+
+/// Page numbers are below this.
+pub const PAGES: usize = 100;
+
+/// A page number below [`PAGES`], only parsing makes one.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Page(u8);
+
+impl Page {
+    pub fn number(self) -> i32 {
+        self.0 as i32
+    }
+
+    fn bit(self) -> u128 {
+        1 << self.0
+    }
+
+    // the lowest page in a bitset
+    fn lowest(set: u128) -> Self {
+        Self(set.trailing_zeros() as u8)
+    }
+}
+
+impl fmt::Debug for Page {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Page {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Which pages must come before which, one bitset per page.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PageOrdering {
+    before: [u128; PAGES],
+}
+
+pub type PageUpdates = Vec<Vec<Page>>;
+
+// the pages of an update as a bitset
+fn page_set(page_update: &[Page]) -> u128 {
+    page_update.iter().fold(0, |set, &page| set | page.bit())
+}
+
+impl PageOrdering {
+    pub fn new() -> Self {
+        Self { before: [0; PAGES] }
+    }
+
+    /// `page1` must come before `page2`.
+    pub fn insert(&mut self, page1: Page, page2: Page) {
+        self.before[page2.0 as usize] |= page1.bit();
+    }
+
+    pub fn must_precede(&self, page1: Page, page2: Page) -> bool {
+        self.before(page2) & page1.bit() != 0
+    }
+
+    fn before(&self, page: Page) -> u128 {
+        self.before[page.0 as usize]
+    }
+}
+
+impl Default for PageOrdering {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// each page with the pages that must come before it
+impl fmt::Debug for PageOrdering {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let all = || (0..PAGES as u8).map(Page);
+        let pages = |set: u128| all().filter(move |page| set & page.bit() != 0);
+        f.debug_map()
+            .entries(
+                all()
+                    .filter(|&page| self.before(page) != 0)
+                    .map(|page| (page, pages(self.before(page)).collect::<Vec<_>>())),
+            )
+            .finish()
+    }
+}
+
+/// 1-based position of a problem in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRulesError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: PageRulesErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageRulesErrorKind {
+    /// No blank line between the rules and the updates.
+    MissingUpdates,
+    /// Not two pages separated by `|`.
+    InvalidRule,
+    InvalidPage(String),
+    /// Outside of `0..PAGES`.
+    PageOutOfRange(i32),
+}
+
+impl std::fmt::Display for PageRulesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            PageRulesErrorKind::MissingUpdates => {
+                write!(f, "expected a blank line before the page updates")
+            }
+            PageRulesErrorKind::InvalidRule => write!(f, "expected a rule like 47|53"),
+            PageRulesErrorKind::InvalidPage(token) => write!(f, "invalid page number '{}'", token),
+            PageRulesErrorKind::PageOutOfRange(page) => {
+                write!(f, "page {} is outside of 0..{}", page, PAGES)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PageRulesError {}
+
+/// Something parsed, but maybe not as meant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageRulesWarning {
+    /// Only outside of strict mode.
+    SkippedToken {
+        line: usize,
+        column: usize,
+        token: String,
+    },
+    /// No single middle page.
+    EvenLength { line: usize, len: usize },
+}
+
+impl std::fmt::Display for PageRulesWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SkippedToken {
+                line,
+                column,
+                token,
+            } => write!(f, "line {}, column {}: skipped '{}'", line, column, token),
+            Self::EvenLength { line, len } => write!(
+                f,
+                "line {}: update has {} pages, the middle one is ambiguous",
+                line, len
+            ),
+        }
+    }
+}
+
+pub struct PageRules {
+    pub page_ordering: PageOrdering,
+    pub page_updates: PageUpdates,
+    pub warnings: Vec<PageRulesWarning>,
+}
+
+fn parse_page(line: usize, column: usize, token: &str) -> Result<Page, PageRulesError> {
+    let error = |kind| PageRulesError { line, column, kind };
+    let page: i32 = token
+        .parse()
+        .map_err(|_| error(PageRulesErrorKind::InvalidPage(token.to_string())))?;
+    if !(0..PAGES as i32).contains(&page) {
+        return Err(error(PageRulesErrorKind::PageOutOfRange(page)));
+    }
+    Ok(Page(page as u8))
+}
+
+// the trimmed pieces of `line` between `separator`s, with their 1-based columns
+fn tokens(line: &str, separator: char) -> impl Iterator<Item = (usize, &str)> {
+    line.split(separator).scan(0, move |offset, piece| {
+        let start = *offset + piece.len() - piece.trim_start().len();
+        *offset += piece.len() + separator.len_utf8();
+        Some((start + 1, piece.trim()))
+    })
+}
+
+/// Rules, a blank line, then updates. Strict mode rejects anything in an
+/// update that isn't a page number instead of skipping it with a warning.
+pub fn parse_page_rules(input: &str, strict: bool) -> Result<PageRules, PageRulesError> {
+    let mut page_ordering = PageOrdering::new();
+    let mut page_updates = vec![];
+    let mut warnings = vec![];
+
+    let mut in_updates = false;
+
+    for (i, line) in input.lines().enumerate() {
+        let line_number = i + 1;
+        let line = line.trim_end_matches('\r');
+
+        if line.trim().is_empty() {
+            in_updates = true;
+            continue;
+        }
+
+        if !in_updates {
+            let (page1, page2) = match tokens(line, '|').collect::<Vec<_>>()[..] {
+                [(column1, page1), (column2, page2)] => (
+                    parse_page(line_number, column1, page1)?,
+                    parse_page(line_number, column2, page2)?,
+                ),
+                _ => {
+                    return Err(PageRulesError {
+                        line: line_number,
+                        column: 1,
+                        kind: PageRulesErrorKind::InvalidRule,
+                    })
+                }
+            };
+            page_ordering.insert(page1, page2);
+            continue;
+        }
+
+        let mut page_update = vec![];
+        for (column, token) in tokens(line, ',') {
+            match parse_page(line_number, column, token) {
+                Ok(page) => page_update.push(page),
+                Err(e) if strict || matches!(e.kind, PageRulesErrorKind::PageOutOfRange(_)) => {
+                    return Err(e)
+                }
+                Err(_) => warnings.push(PageRulesWarning::SkippedToken {
+                    line: line_number,
+                    column,
+                    token: token.to_string(),
+                }),
+            }
+        }
+
+        if page_update.len().is_multiple_of(2) {
+            warnings.push(PageRulesWarning::EvenLength {
+                line: line_number,
+                len: page_update.len(),
+            });
+        }
+        page_updates.push(page_update);
+    }
+
+    if !in_updates {
+        return Err(PageRulesError {
+            line: input.lines().count() + 1,
+            column: 1,
+            kind: PageRulesErrorKind::MissingUpdates,
+        });
+    }
+
+    Ok(PageRules {
+        page_ordering,
+        page_updates,
+        warnings,
+    })
+}
+
+/// Every page must have a rule putting each earlier page before it.
+pub fn is_valid_page_update(page_update: &[Page], page_ordering: &PageOrdering) -> bool {
+    let mut prev = 0;
+
+    for &page in page_update {
+        if prev & !page_ordering.before(page) != 0 {
+            return false;
+        }
+        prev |= page.bit();
+    }
+
+    true
+}
+
+/// Pages of an update in an order that satisfies every rule between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopologicalOrder {
+    pub pages: Vec<Page>,
+    /// False when the rules leave some pages free to swap.
+    pub unique: bool,
+}
+
+/// Contradictory rules, each page must come before the next and the last
+/// before the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    pub cycle: Vec<Page>,
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rules form a cycle: ")?;
+        for page in &self.cycle {
+            write!(f, "{} -> ", page)?;
+        }
+        write!(f, "{}", self.cycle[0])
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+pub fn topological_order(
+    page_update: &[Page],
+    page_ordering: &PageOrdering,
+) -> Result<TopologicalOrder, CycleError> {
+    let mut pages = page_update.to_vec();
+    let unique = sort_invalid_page_update(&mut pages, page_ordering)?;
+    Ok(TopologicalOrder { pages, unique })
+}
+
+// every page left over by Kahn's algorithm still has a left over predecessor,
+// so walking back through them must come round to a page already seen
+fn find_cycle(remaining: u128, page_ordering: &PageOrdering) -> Vec<Page> {
+    let mut walk = vec![Page::lowest(remaining)];
+
+    loop {
+        let current = walk[walk.len() - 1];
+        let prev = Page::lowest(page_ordering.before(current) & remaining);
+
+        if let Some(start) = walk.iter().position(|&page| page == prev) {
+            return walk[start..].iter().rev().copied().collect();
+        }
+        walk.push(prev);
+    }
+}
+
+/// Kahn's algorithm in place over the rules between the update's pages,
+/// unconstrained pages keep their relative order. Returns whether the rules
+/// allowed only this order, the update is left part sorted on a cycle.
+pub fn sort_invalid_page_update(
+    page_update: &mut [Page],
+    page_ordering: &PageOrdering,
+) -> Result<bool, CycleError> {
+    let mut remaining = page_set(page_update);
+    let mut unique = true;
+
+    for placed in 0..page_update.len() {
+        let mut ready = (placed..page_update.len())
+            .filter(|&i| page_ordering.before(page_update[i]) & remaining == 0);
+
+        let Some(next) = ready.next() else {
+            return Err(CycleError {
+                cycle: find_cycle(remaining, page_ordering),
+            });
+        };
+        unique &= ready.next().is_none();
+
+        page_update[placed..=next].rotate_right(1);
+        remaining &= !page_update[placed].bit();
+    }
+
+    Ok(unique)
+}
+
+pub fn bucket_page_updates<'a>(
+    page_updates: &'a [Vec<Page>],
+    page_ordering: &PageOrdering,
+) -> (Vec<&'a [Page]>, Vec<&'a [Page]>) {
+    page_updates
+        .iter()
+        .map(Vec::as_slice)
+        .partition(|page_update| is_valid_page_update(page_update, page_ordering))
+}
+
+fn middle(page_update: &[Page]) -> i32 {
+    page_update
+        .get(page_update.len() / 2)
+        .map_or(0, |page| page.number())
+}
+
+pub fn get_middles_sum<U: AsRef<[Page]>>(page_updates: &[U]) -> i32 {
+    page_updates
+        .iter()
+        .map(|page_update| middle(page_update.as_ref()))
+        .sum()
+}
+
+/// Sorts the invalid updates through one reused buffer and sums their middle
+/// pages, `on_non_unique` sees every sorted update the rules leave ambiguous.
+pub fn sorted_invalid_middles_sum<U: AsRef<[Page]>>(
+    page_updates: &[U],
+    page_ordering: &PageOrdering,
+    mut on_non_unique: impl FnMut(&[Page]),
+) -> Result<i32, CycleError> {
+    let mut sorted = vec![];
+    let mut sum = 0;
+
+    for page_update in page_updates {
+        let page_update = page_update.as_ref();
+        if is_valid_page_update(page_update, page_ordering) {
+            continue;
+        }
+        sorted.clear();
+        sorted.extend_from_slice(page_update);
+        if !sort_invalid_page_update(&mut sorted, page_ordering)? {
+            on_non_unique(&sorted);
+        }
+        sum += middle(&sorted);
+    }
+
+    Ok(sum)
+}
+
+pub struct Day05 {
+    page_ordering: PageOrdering,
+    page_updates: PageUpdates,
+    warnings: Vec<PageRulesWarning>,
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+    const PARAMS: &'static [Param] = &[Param {
+        name: "strict",
+        default: "false",
+        example: "false",
+    }];
+
+    fn parse(input: &str, params: &Params) -> Result<Self, AocError> {
+        let page_rules = parse_page_rules(input, params.get("strict")?)
+            .map_err(|e| AocError::Parse(e.to_string()))?;
+
+        Ok(Self {
+            page_ordering: page_rules.page_ordering,
+            page_updates: page_rules.page_updates,
+            warnings: page_rules.warnings,
+        })
+    }
+
+    fn warnings(&self) -> Vec<String> {
+        self.warnings.iter().map(ToString::to_string).collect()
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let sum: i32 = self
+            .page_updates
+            .iter()
+            .filter(|page_update| is_valid_page_update(page_update, &self.page_ordering))
+            .map(|page_update| middle(page_update))
+            .sum();
+        Ok(sum.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let sum = sorted_invalid_middles_sum(&self.page_updates, &self.page_ordering, |_| {})
+            .map_err(|e| AocError::NoSolution(e.to_string()))?;
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn pages(numbers: &[u8]) -> Vec<Page> {
+        numbers.iter().map(|&number| Page(number)).collect()
+    }
+
+    fn example() -> PageRules {
+        let page_rules = parse_page_rules(EXAMPLE, true).unwrap();
+        assert!(page_rules.warnings.is_empty());
+        page_rules
+    }
+
+    #[test]
+    fn valid_middles_sum_example() {
+        let page_rules = example();
+        let (valid, _) = bucket_page_updates(&page_rules.page_updates, &page_rules.page_ordering);
+        assert_eq!(get_middles_sum(&valid), 143);
+    }
+
+    #[test]
+    fn sorted_invalid_middles_sum_example() {
+        let page_rules = example();
+        let page_ordering = page_rules.page_ordering;
+        let (_, invalid) = bucket_page_updates(&page_rules.page_updates, &page_ordering);
+        let mut invalid: Vec<Vec<Page>> = invalid.into_iter().map(<[Page]>::to_vec).collect();
+        for page_update in invalid.iter_mut() {
+            assert_eq!(
+                sort_invalid_page_update(page_update, &page_ordering),
+                Ok(true)
+            );
+        }
+        assert_eq!(get_middles_sum(&invalid), 123);
+        assert_eq!(
+            sorted_invalid_middles_sum(&page_rules.page_updates, &page_ordering, |_| {
+                panic!("the example rules are total")
+            }),
+            Ok(123)
+        );
+    }
+
+    fn rules(rules: &str) -> PageOrdering {
+        parse_page_rules(&format!("{}\n\n", rules), true)
+            .unwrap()
+            .page_ordering
+    }
+
+    #[test]
+    fn partial_rules_are_not_unique() {
+        let page_ordering = rules("1|3\n2|3");
+        assert_eq!(
+            topological_order(&pages(&[3, 2, 1]), &page_ordering),
+            Ok(TopologicalOrder {
+                pages: pages(&[2, 1, 3]),
+                unique: false
+            })
+        );
+        assert!(
+            topological_order(&pages(&[3, 1]), &page_ordering)
+                .unwrap()
+                .unique
+        );
+    }
+
+    #[test]
+    fn contradictory_rules_name_the_cycle() {
+        let page_ordering = rules("1|2\n2|3\n3|1\n4|1");
+        let err = topological_order(&pages(&[4, 3, 2, 1]), &page_ordering).unwrap_err();
+        assert_eq!(err.cycle.len(), 3);
+        for (i, &page) in err.cycle.iter().enumerate() {
+            let next = err.cycle[(i + 1) % err.cycle.len()];
+            assert!(page_ordering.must_precede(page, next));
+        }
+        assert_eq!(
+            CycleError {
+                cycle: pages(&[1, 2, 3])
+            }
+            .to_string(),
+            "rules form a cycle: 1 -> 2 -> 3 -> 1"
+        );
+    }
+
+    #[test]
+    fn errors_have_line_and_column() {
+        let err = parse_page_rules("1|2\n3-4\n\n1,2,3", false).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a rule like 47|53"
+        );
+
+        let err = parse_page_rules("1|2\n3| x4\n\n1,2,3", false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            PageRulesError {
+                line: 2,
+                column: 4,
+                kind: PageRulesErrorKind::InvalidPage("x4".to_string())
+            }
+        );
+
+        let err = parse_page_rules("1|2\n\n1,100,3", false).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 3: page 100 is outside of 0..100"
+        );
+
+        let err = parse_page_rules("-1|2\n\n1,2,3", false).err().unwrap();
+        assert_eq!(err.kind, PageRulesErrorKind::PageOutOfRange(-1));
+
+        let err = parse_page_rules("1|2\n", false).err().unwrap();
+        assert_eq!(err.kind, PageRulesErrorKind::MissingUpdates);
+    }
+
+    #[test]
+    fn strict_mode_rejects_typos() {
+        let input = "75|29\r\n\r\n75,4x,29\r\n75,29,13\r\n";
+        let err = parse_page_rules(input, true).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 4: invalid page number '4x'"
+        );
+
+        let page_rules = parse_page_rules(input, false).unwrap();
+        assert_eq!(
+            page_rules.page_updates,
+            [pages(&[75, 29]), pages(&[75, 29, 13])]
+        );
+        assert_eq!(
+            page_rules.warnings,
+            [
+                PageRulesWarning::SkippedToken {
+                    line: 3,
+                    column: 4,
+                    token: "4x".to_string()
+                },
+                PageRulesWarning::EvenLength { line: 3, len: 2 },
+            ]
+        );
+    }
+}
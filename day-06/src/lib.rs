@@ -0,0 +1,439 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, AocError, Direction, Grid, Params, Point, Solution};
+
+pub fn get_lab_map(input: &str) -> Result<Grid<char>, String> {
+    input.parse()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub pos: Point,
+    pub dir: Direction,
+}
+
+/// The single `^`, `>`, `v` or `<` on the map.
+pub fn find_start(lab_map: &Grid<char>) -> Result<Guard, String> {
+    let mut guards = lab_map
+        .iter()
+        .filter_map(|(pos, &ch)| Direction::from_arrow(ch).map(|dir| Guard { pos, dir }));
+
+    let guard = guards.next().ok_or("No guard on the lab map")?;
+    if let Some(other) = guards.next() {
+        return Err(format!(
+            "More than one guard, at line {} and line {}",
+            guard.pos.row + 1,
+            other.pos.row + 1
+        ));
+    }
+    Ok(guard)
+}
+
+/// Every state of the guard in order, a turn on the spot is a state of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Patrol {
+    pub path: Vec<Guard>,
+    /// Where in `path` the loop starts, when the guard never leaves.
+    pub loop_start: Option<usize>,
+}
+
+impl Patrol {
+    pub fn cells(&self) -> HashSet<Point> {
+        self.path.iter().map(|guard| guard.pos).collect()
+    }
+}
+
+/// An obstruction that traps the guard, and the loop they end up walking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardLoop {
+    pub obstruction: Point,
+    /// Steps for one round of the loop.
+    pub length: usize,
+    pub cells: HashSet<Point>,
+}
+
+impl GuardLoop {
+    fn new(obstruction: Point, cycle: &[Guard]) -> Self {
+        let n = cycle.len();
+        Self {
+            obstruction,
+            length: (0..n)
+                .filter(|&i| cycle[i].pos != cycle[(i + 1) % n].pos)
+                .count(),
+            cells: cycle.iter().map(|guard| guard.pos).collect(),
+        }
+    }
+}
+
+/// Walks until the guard leaves the map or repeats a state, with an extra
+/// `obstruction` treated like a `#`.
+pub fn patrol(lab_map: &Grid<char>, start: Guard, obstruction: Option<Point>) -> Patrol {
+    let mut path = vec![start];
+    let mut seen: HashMap<Guard, usize> = HashMap::from([(start, 0)]);
+    let mut guard = start;
+
+    loop {
+        let next = guard.pos + guard.dir;
+        let Some(&next_ch) = lab_map.get(next) else {
+            return Patrol {
+                path,
+                loop_start: None,
+            };
+        };
+
+        if next_ch == '#' || Some(next) == obstruction {
+            guard.dir = guard.dir.turn_right();
+        } else {
+            guard.pos = next;
+        }
+
+        if let Some(&i) = seen.get(&guard) {
+            return Patrol {
+                path,
+                loop_start: Some(i),
+            };
+        }
+        seen.insert(guard, path.len());
+        path.push(guard);
+    }
+}
+
+/// Where walking straight ends, so a loop check hops from turn to turn.
+struct JumpTable {
+    /// The cell before the next `#` in each direction, `None` off the map.
+    stops: Grid<[Option<Point>; 4]>,
+    /// Turns already taken in the current check, stamped with its number.
+    seen: Grid<[u32; 4]>,
+    generation: u32,
+}
+
+impl JumpTable {
+    fn new(lab_map: &Grid<char>) -> Self {
+        let mut stops = Grid::new(lab_map.rows(), lab_map.cols(), [None; 4]);
+
+        // fill each direction so the cell ahead has its answer already
+        let forwards: Vec<Point> = lab_map.positions().collect();
+        for dir in Direction::ALL {
+            let order: Box<dyn Iterator<Item = &Point>> = match dir {
+                Direction::Up | Direction::Left => Box::new(forwards.iter()),
+                Direction::Down | Direction::Right => Box::new(forwards.iter().rev()),
+            };
+            for &pos in order {
+                stops[pos][dir as usize] = match lab_map.get(pos + dir) {
+                    None => None,
+                    Some('#') => Some(pos),
+                    Some(_) => stops[pos + dir][dir as usize],
+                };
+            }
+        }
+
+        Self {
+            seen: Grid::new(lab_map.rows(), lab_map.cols(), [0; 4]),
+            stops,
+            generation: 0,
+        }
+    }
+
+    // where the guard stops walking straight, the extra obstruction included
+    fn stop(&self, guard: Guard, obstruction: Point) -> Option<Point> {
+        let stop = self.stops[guard.pos][guard.dir as usize];
+
+        let ahead = obstruction - guard.pos;
+        let distance = guard.pos.manhattan(obstruction);
+        let in_line = guard.dir.delta() * distance == ahead;
+        let before_stop = stop.is_none_or(|stop| distance <= guard.pos.manhattan(stop));
+
+        if distance > 0 && in_line && before_stop {
+            Some(obstruction - guard.dir)
+        } else {
+            stop
+        }
+    }
+
+    /// Whether the guard starting `from` loops with `obstruction` placed.
+    fn traps(&mut self, from: Guard, obstruction: Point) -> bool {
+        self.generation += 1;
+        let mut guard = from;
+
+        while let Some(pos) = self.stop(guard, obstruction) {
+            guard = Guard {
+                pos,
+                dir: guard.dir.turn_right(),
+            };
+            let seen = &mut self.seen[pos][guard.dir as usize];
+            if *seen == self.generation {
+                return true;
+            }
+            *seen = self.generation;
+        }
+
+        false
+    }
+}
+
+// each cell the guard walks into, with the state just before they first do,
+// an obstruction there changes nothing until then
+fn candidates(lab_map: &Grid<char>, start: Guard) -> Vec<(Point, Guard)> {
+    let path = patrol(lab_map, start, None).path;
+    let mut tried = HashSet::from([start.pos]);
+
+    path.windows(2)
+        .filter(|w| w[0].pos != w[1].pos && tried.insert(w[1].pos))
+        .map(|w| (w[1].pos, w[0]))
+        .collect()
+}
+
+/// How many cells trap the guard when blocked, the start itself excluded.
+pub fn count_loops(lab_map: &Grid<char>, start: Guard) -> usize {
+    let mut jumps = JumpTable::new(lab_map);
+    candidates(lab_map, start)
+        .into_iter()
+        .filter(|&(obstruction, from)| jumps.traps(from, obstruction))
+        .count()
+}
+
+/// The loop behind each obstruction [`count_loops`] counts.
+pub fn find_loops(lab_map: &Grid<char>, start: Guard) -> Vec<GuardLoop> {
+    let mut jumps = JumpTable::new(lab_map);
+    candidates(lab_map, start)
+        .into_iter()
+        .filter(|&(obstruction, from)| jumps.traps(from, obstruction))
+        .map(|(obstruction, from)| {
+            let trapped = patrol(lab_map, from, Some(obstruction));
+            let i = trapped.loop_start.expect("the jump table found a loop");
+            GuardLoop::new(obstruction, &trapped.path[i..])
+        })
+        .collect()
+}
+
+pub fn count_guard(lab_map: &Grid<char>, start: Guard) -> (usize, usize) {
+    (
+        patrol(lab_map, start, None).cells().len(),
+        count_loops(lab_map, start),
+    )
+}
+
+/// Marks an obstruction candidate in [`Frames`].
+pub const OBSTRUCTION: char = 'O';
+
+// `|` and `-` for walking through a cell, `+` once it's been crossed both ways,
+// the start and the obstructions stay as they are
+fn mark(cell: char, dir: Direction) -> char {
+    let vertical = matches!(dir, Direction::Up | Direction::Down);
+    match (cell, vertical) {
+        ('.', true) => '|',
+        ('.', false) => '-',
+        ('|', false) | ('-', true) => '+',
+        (cell, _) => cell,
+    }
+}
+
+/// The lab map after each state of the patrol, drawn like the puzzle text
+/// with the guard as an arrow.
+pub struct Frames<'a> {
+    canvas: Grid<char>,
+    path: &'a [Guard],
+    step: usize,
+}
+
+impl<'a> Frames<'a> {
+    pub fn new(lab_map: &Grid<char>, path: &'a [Guard], obstructions: &[Point]) -> Self {
+        let mut canvas = lab_map.clone();
+        for &obstruction in obstructions {
+            canvas[obstruction] = OBSTRUCTION;
+        }
+        Self {
+            canvas,
+            path,
+            step: 0,
+        }
+    }
+}
+
+impl Iterator for Frames<'_> {
+    type Item = Grid<char>;
+
+    fn next(&mut self) -> Option<Grid<char>> {
+        let guard = *self.path.get(self.step)?;
+        self.step += 1;
+
+        let cell = &mut self.canvas[guard.pos];
+        *cell = mark(*cell, guard.dir);
+
+        let mut frame = self.canvas.clone();
+        frame[guard.pos] = guard.dir.arrow();
+        Some(frame)
+    }
+}
+
+pub struct Day06 {
+    lab_map: Grid<char>,
+    start: Guard,
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        let lab_map = get_lab_map(input)?;
+        Ok(Self {
+            start: find_start(&lab_map)?,
+            lab_map,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(patrol(&self.lab_map, self.start, None).cells().len().into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(count_loops(&self.lab_map, self.start).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::rng::XorShift;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn example() -> (Grid<char>, Guard) {
+        let lab_map = get_lab_map(EXAMPLE).unwrap();
+        let start = find_start(&lab_map).unwrap();
+        (lab_map, start)
+    }
+
+    #[test]
+    fn count_guard_example() {
+        let (lab_map, start) = example();
+        assert_eq!(count_guard(&lab_map, start), (41, 6));
+    }
+
+    #[test]
+    fn any_start_direction() {
+        let lab_map = get_lab_map("#..\n..<\n...").unwrap();
+        let start = find_start(&lab_map).unwrap();
+        assert_eq!(start.dir, Direction::Left);
+
+        let path = patrol(&lab_map, start, None).path;
+        assert_eq!(
+            path.iter().map(|guard| guard.pos).collect::<Vec<_>>(),
+            [Point::new(1, 2), Point::new(1, 1), Point::new(1, 0)]
+        );
+
+        assert!(find_start(&get_lab_map("...").unwrap()).is_err());
+        assert!(find_start(&get_lab_map("^.\n.v").unwrap()).is_err());
+    }
+
+    #[test]
+    fn loops_report_their_geometry() {
+        let (lab_map, start) = example();
+        let loops = find_loops(&lab_map, start);
+
+        // right next to the start, the guard walks rows 1 to 6 and columns 4 to 8
+        let rectangle = loops
+            .iter()
+            .find(|guard_loop| guard_loop.obstruction == Point::new(6, 3))
+            .unwrap();
+        assert_eq!(rectangle.length, 18);
+        assert_eq!(rectangle.cells.len(), 18);
+        assert!(rectangle.cells.contains(&Point::new(1, 8)));
+
+        // crosses its own path, so visits some cells twice
+        let crossing = loops
+            .iter()
+            .find(|guard_loop| guard_loop.obstruction == Point::new(8, 3))
+            .unwrap();
+        assert_eq!((crossing.length, crossing.cells.len()), (34, 31));
+    }
+
+    fn naive_traps(lab_map: &Grid<char>, start: Guard, obstruction: Point) -> bool {
+        patrol(lab_map, start, Some(obstruction))
+            .loop_start
+            .is_some()
+    }
+
+    #[test]
+    fn jump_table_matches_simulation_example() {
+        let (lab_map, start) = example();
+        let mut jumps = JumpTable::new(&lab_map);
+        for (pos, &ch) in lab_map.iter() {
+            if ch == '.' {
+                assert_eq!(
+                    jumps.traps(start, pos),
+                    naive_traps(&lab_map, start, pos),
+                    "obstruction at {:?}",
+                    pos
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn jump_table_matches_simulation_random() {
+        let mut rng = XorShift::new(0x9e37_79b9_7f4a_7c15);
+        let mut next = |bound: u64| rng.below(bound);
+
+        let mut total = 0;
+        for _ in 0..200 {
+            let size = 3 + next(8) as usize;
+            let mut lab_map = Grid::new(size, size, '.');
+            for pos in lab_map.positions().collect::<Vec<_>>() {
+                if next(5) == 0 {
+                    lab_map[pos] = '#';
+                }
+            }
+            let pos = Point::new(next(size as u64) as i32, next(size as u64) as i32);
+            let dir = Direction::ALL[next(4) as usize];
+            lab_map[pos] = dir.arrow();
+            let start = Guard { pos, dir };
+
+            let expected: Vec<Point> = lab_map
+                .find_all(&'.')
+                .filter(|&obstruction| {
+                    patrol(&lab_map, start, None).cells().contains(&obstruction)
+                        && naive_traps(&lab_map, start, obstruction)
+                })
+                .collect();
+            let mut found: Vec<Point> = find_loops(&lab_map, start)
+                .into_iter()
+                .map(|guard_loop| guard_loop.obstruction)
+                .collect();
+            found.sort();
+            assert_eq!(found, expected, "\n{}", lab_map);
+            assert_eq!(count_loops(&lab_map, start), expected.len());
+            total += expected.len();
+        }
+        assert!(total > 20, "only {} loops in all maps", total);
+    }
+
+    #[test]
+    fn frames_draw_the_path() {
+        let (lab_map, start) = example();
+        let path = patrol(&lab_map, start, None).path;
+        let obstructions: Vec<Point> = find_loops(&lab_map, start)
+            .into_iter()
+            .map(|guard_loop| guard_loop.obstruction)
+            .collect();
+
+        let frames: Vec<Grid<char>> = Frames::new(&lab_map, &path, &obstructions).collect();
+        assert_eq!(frames.len(), path.len());
+        assert_eq!(frames[0][start.pos], '^');
+        assert_eq!(
+            frames[frames.len() - 1].to_string(),
+            "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+..+-+-+#|.
+..|.|.|.|.
+.#+O^-+-+.
+.+----OO#.
+#O-O--+|..
+......#v.."
+        );
+    }
+}
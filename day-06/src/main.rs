@@ -1,134 +1,124 @@
-use std::collections::{HashMap, HashSet};
-
-#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
-struct Position(i32, i32);
-
-fn read_input<P>(filename: P) -> std::io::Result<String>
-where
-    P: AsRef<std::path::Path>,
-{
-    std::fs::read_to_string(filename)
-}
-
-fn get_lab_map(input: &str) -> Vec<Vec<char>> {
-    input.lines().map(|l| l.chars().collect()).collect()
-}
-
-fn find_start_position(lab_map: &[Vec<char>]) -> Position {
-    for (i, row) in lab_map.iter().enumerate() {
-        for (j, ch) in row.iter().enumerate() {
-            if *ch == '^' {
-                return Position(i as i32, j as i32);
-            }
+use std::{fs, io, path::PathBuf, thread, time::Duration};
+
+use aoc_common::{AocError, Args, Input, Point, Solution};
+use day_06::{count_loops, find_loops, find_start, get_lab_map, patrol, Day06, Frames};
+
+const USAGE: &str = "[--loops] [--visualise [--delay <ms>] [--step]] [--frames <dir>] \
+     [--highlight] <filename|-|--example>";
+
+const DEFAULT_DELAY_MS: u64 = 50;
+
+// redraws in place, `--step` waits for enter between frames instead of the delay
+fn animate(frames: Frames, total: usize, delay: Duration, step: bool) -> Result<(), AocError> {
+    for (i, frame) in frames.enumerate() {
+        print!("\x1b[2J\x1b[H{}\n\nstep {}/{}", frame, i + 1, total);
+        if step {
+            println!(" (enter for the next step)");
+            io::stdin()
+                .read_line(&mut String::new())
+                .map_err(|error| AocError::Io {
+                    source: "<stdin>".to_string(),
+                    error,
+                })?;
+        } else {
+            println!();
+            thread::sleep(delay);
         }
     }
-    panic!("No starting position");
-}
-
-const DIRECTIONS: [(i32, i32); 4] = [
-    (-1, 0), // Up
-    (0, 1),  // Right
-    (1, 0),  // Down
-    (0, -1), // Left
-];
-
-fn get_next(pos: &Position, dir_idx: usize) -> Position {
-    let (dx, dy) = DIRECTIONS[dir_idx];
-    Position(pos.0 + dx, pos.1 + dy)
-}
-
-fn turn(dir_idx: usize) -> usize {
-    (dir_idx + 1) % 4 // Clockwise rotation
+    Ok(())
 }
 
-fn count_guard(lab_map: &[Vec<char>]) -> (usize, usize) {
-    let rows = lab_map.len() as i32;
-    let cols = lab_map[0].len() as i32;
-
-    let mut visited: HashSet<Position> = HashSet::new();
-    let mut obstructions: HashSet<Position> = HashSet::new();
-    let mut loop_cache: HashMap<(Position, usize), bool> = HashMap::new();
-    let mut curr_pos = find_start_position(lab_map);
-    let mut curr_dir = 0; // Using integer direction index (0: Up, 1: Right, etc.)
-
-    loop {
-        visited.insert(curr_pos);
-
-        let next = get_next(&curr_pos, curr_dir);
-
-        if next.0 < 0 || next.1 < 0 || next.0 >= rows || next.1 >= cols {
-            break; // Out of bounds
-        }
-
-        let next_ch = lab_map[next.0 as usize][next.1 as usize];
-        if next_ch == '#' {
-            curr_dir = turn(curr_dir);
-            continue;
-        }
+fn write_frames(frames: Frames, dir: &PathBuf) -> Result<usize, AocError> {
+    let io_error = |path: &PathBuf| {
+        let source = path.display().to_string();
+        move |error| AocError::Io { source, error }
+    };
 
-        if !obstructions.contains(&next) {
-            let cache_key = (curr_pos, curr_dir);
-            loop_cache
-                .entry(cache_key)
-                .or_insert_with(|| check_for_loop(&curr_pos, curr_dir, lab_map, rows, cols));
-
-            if *loop_cache.get(&cache_key).unwrap() {
-                obstructions.insert(next);
-            }
-        }
+    fs::create_dir_all(dir).map_err(io_error(dir))?;
 
-        curr_pos = next;
+    let mut count = 0;
+    for (i, frame) in frames.enumerate() {
+        let path = dir.join(format!("frame-{:05}.txt", i + 1));
+        fs::write(&path, format!("{}\n", frame)).map_err(io_error(&path))?;
+        count += 1;
     }
-
-    (visited.len(), obstructions.len())
+    Ok(count)
 }
 
-fn check_for_loop(
-    start_pos: &Position,
-    start_dir: usize,
-    lab_map: &[Vec<char>],
-    rows: i32,
-    cols: i32,
-) -> bool {
-    let mut visited = HashSet::new();
-    let mut curr_pos = *start_pos;
-    let mut curr_dir = turn(start_dir);
-
-    loop {
-        visited.insert(curr_pos);
-
-        let next = get_next(&curr_pos, curr_dir);
-        if next.0 < 0 || next.1 < 0 || next.0 >= rows || next.1 >= cols {
-            return false;
-        }
-
-        let next_ch = lab_map[next.0 as usize][next.1 as usize];
-        if next_ch == '#' {
-            curr_dir = turn(curr_dir);
-            continue;
-        }
-
-        if next == *start_pos {
-            return true; // Loop detected
-        }
-
-        curr_pos = next;
+fn main() -> Result<(), AocError> {
+    let mut args = Args::from_env(USAGE);
+    let list_loops = args.flag("--loops");
+    let visualise = args.flag("--visualise");
+    let delay = args.option::<u64>("--delay")?;
+    let step = args.flag("--step");
+    let frames_dir = args.option::<PathBuf>("--frames")?;
+    let highlight = args.flag("--highlight");
+    let source = args.input(Day06::EXAMPLE)?;
+    if !visualise && (step || delay.is_some()) {
+        return Err(args.error("--delay and --step require --visualise".to_string()));
+    }
+    if highlight && !visualise && frames_dir.is_none() {
+        return Err(args.error("--highlight requires --visualise or --frames".to_string()));
+    }
+    if step && matches!(source, Input::Stdin) {
+        return Err(
+            args.error("--step can't be combined with reading the map from stdin".to_string())
+        );
+    }
+    args.finish()?;
+
+    let input = source.read()?;
+
+    let lab_map = get_lab_map(&input)?;
+    let start = find_start(&lab_map)?;
+
+    let patrol = patrol(&lab_map, start, None);
+    // the loop geometry re-walks every loop, so only when it's shown
+    let loops = (list_loops || highlight).then(|| find_loops(&lab_map, start));
+
+    let obstructions: Vec<Point> = match &loops {
+        Some(loops) if highlight => loops
+            .iter()
+            .map(|guard_loop| guard_loop.obstruction)
+            .collect(),
+        _ => vec![],
+    };
+
+    if visualise {
+        animate(
+            Frames::new(&lab_map, &patrol.path, &obstructions),
+            patrol.path.len(),
+            Duration::from_millis(delay.unwrap_or(DEFAULT_DELAY_MS)),
+            step,
+        )?;
     }
-}
-
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let filename = &args[1];
-
-    let input = read_input(filename).expect("Failed to read file");
-
-    println!("{}", input);
 
-    let lab_map = get_lab_map(&input);
+    if let Some(dir) = &frames_dir {
+        let count = write_frames(Frames::new(&lab_map, &patrol.path, &obstructions), dir)?;
+        println!("wrote {} frames to {}", count, dir.display());
+    }
 
-    let (guard_positions, obstructions) = count_guard(&lab_map);
+    let guard_positions = patrol.cells().len();
+    let obstruction_count = match &loops {
+        Some(loops) => loops.len(),
+        None => count_loops(&lab_map, start),
+    };
     println!(
         "guard positions: {}\nobstructions: {}",
-        guard_positions, obstructions
+        guard_positions, obstruction_count
     );
+
+    if list_loops {
+        for guard_loop in loops.iter().flatten() {
+            println!(
+                "obstruction at ({},{}): loop of {} steps over {} cells",
+                guard_loop.obstruction.row,
+                guard_loop.obstruction.col,
+                guard_loop.length,
+                guard_loop.cells.len()
+            );
+        }
+    }
+
+    Ok(())
 }
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, AocError, Params, Solution};
+
+#[derive(Debug)]
+pub enum CalibrationError {
+    ParseError(String),
+    UnsupportedOperatorError,
+}
+
+impl std::fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ParseError(e) => write!(f, "ParseError: {}", e),
+            Self::UnsupportedOperatorError => write!(f, "UnsupportedOperatorError"),
+        }
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
+struct CalibrationEquation {
+    test_value: u64,
+    nums: Vec<u64>,
+}
+
+impl CalibrationEquation {
+    fn calculate(&self, operators: &str) -> Result<u64, CalibrationError> {
+        assert_eq!(operators.len(), self.nums.len() - 1);
+
+        let mut res = self.nums[0];
+
+        for (i, op) in operators.chars().enumerate() {
+            let next = self.nums[i + 1];
+            res = match op {
+                '+' => res + next,
+                '*' => res * next,
+                '|' => res * 10_u64.pow(next.ilog10() + 1) + next,
+                _ => return Err(CalibrationError::UnsupportedOperatorError),
+            };
+        }
+
+        Ok(res)
+    }
+}
+
+impl std::str::FromStr for CalibrationEquation {
+    type Err = CalibrationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (test_value_str, nums_str) = s
+            .split_once(": ")
+            .ok_or(CalibrationError::ParseError("No : delimiter".to_string()))?;
+
+        let test_value = test_value_str.parse::<u64>().map_err(|_| {
+            CalibrationError::ParseError(format!("Failed to parse test_value {}", test_value_str))
+        })?;
+
+        let nums: Vec<u64> = nums_str
+            .split_whitespace()
+            .map(|num_str| {
+                num_str.parse::<u64>().map_err(|_| {
+                    CalibrationError::ParseError(format!("Invalid number: {}", num_str))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { test_value, nums })
+    }
+}
+
+struct OperatorsCache {
+    valid_operators: &'static [char],
+    cache: HashMap<usize, Vec<String>>,
+}
+
+pub const BASIC_OPERATORS: &[char] = &['+', '*'];
+pub const ALL_OPERATORS: &[char] = &['+', '|', '*'];
+
+impl OperatorsCache {
+    fn new(valid_operators: &'static [char]) -> Self {
+        Self {
+            valid_operators,
+            cache: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, length: usize) -> &[String] {
+        let valid_operators = self.valid_operators;
+        self.cache.entry(length).or_insert_with(|| {
+            let mut operators = Vec::with_capacity(valid_operators.len().pow(length as u32));
+            let mut prefix = String::from("");
+            generate_operators(&mut operators, &mut prefix, length, valid_operators);
+
+            operators
+        })
+    }
+}
+
+fn generate_operators(
+    operators: &mut Vec<String>,
+    prefix: &mut String,
+    length: usize,
+    valid_operators: &[char],
+) {
+    if prefix.len() == length {
+        operators.push(prefix.clone());
+        return;
+    }
+
+    for &op in valid_operators {
+        prefix.push(op);
+        generate_operators(operators, prefix, length, valid_operators);
+        prefix.pop();
+    }
+}
+
+pub fn total_calibration(
+    input: &str,
+    valid_operators: &'static [char],
+) -> Result<u64, CalibrationError> {
+    let mut op_cache = OperatorsCache::new(valid_operators);
+
+    input
+        .lines()
+        .map(|line| {
+            let equation = line.parse::<CalibrationEquation>()?;
+
+            for operators in op_cache.get(equation.nums.len() - 1) {
+                if equation.test_value == equation.calculate(operators)? {
+                    return Ok(equation.test_value);
+                }
+            }
+
+            Ok(0)
+        })
+        .sum()
+}
+
+pub struct Day07 {
+    input: String,
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        Ok(Self {
+            input: input.to_string(),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        total_calibration(&self.input, BASIC_OPERATORS)
+            .map(Answer::from)
+            .map_err(|e| AocError::Parse(e.to_string()))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        total_calibration(&self.input, ALL_OPERATORS)
+            .map(Answer::from)
+            .map_err(|e| AocError::Parse(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn total_calibration_example() {
+        assert_eq!(total_calibration(EXAMPLE, BASIC_OPERATORS).unwrap(), 3749);
+    }
+
+    #[test]
+    fn total_calibration_with_concatenation_example() {
+        assert_eq!(total_calibration(EXAMPLE, ALL_OPERATORS).unwrap(), 11387);
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{debug, trace, Answer, AocError, Grid, Params, Point, Solution};
+
+pub struct CityMap {
+    map: Grid<char>,
+    frequencies: HashMap<char, HashSet<Point>>,
+}
+
+impl CityMap {
+    pub fn new(city_map_str: &str) -> Result<Self, String> {
+        let map: Grid<char> = city_map_str.parse()?;
+        let mut frequencies: HashMap<char, HashSet<Point>> = HashMap::new();
+
+        for (antenna, &ch) in map.iter() {
+            if ch != '.' {
+                frequencies.entry(ch).or_default().insert(antenna);
+            }
+        }
+
+        debug!("{}x{}", map.rows(), map.cols());
+        trace!("{}", map);
+
+        Ok(Self { map, frequencies })
+    }
+
+    pub fn anti_nodes(&self, resonant_harmonics: bool) -> HashSet<Point> {
+        let mut anti_nodes = HashSet::new();
+
+        for (frequency, antennas) in self.frequencies.iter() {
+            for antenna1 in antennas.iter() {
+                for antenna2 in antennas.difference(&HashSet::from([*antenna1])) {
+                    let difference = *antenna1 - *antenna2;
+
+                    if !resonant_harmonics {
+                        let possible = *antenna2 - difference;
+                        if self.map.contains(possible) {
+                            anti_nodes.insert(possible);
+                        }
+                        continue;
+                    }
+
+                    let mut possible = *antenna2;
+                    while self.map.contains(possible) {
+                        trace!(
+                            "frequency: {}, a1: {:?}, a2: {:?} possible: {:?}",
+                            frequency,
+                            antenna1,
+                            antenna2,
+                            possible,
+                        );
+
+                        anti_nodes.insert(possible);
+
+                        possible -= difference;
+                    }
+                }
+            }
+        }
+
+        anti_nodes
+    }
+}
+
+pub struct Day08 {
+    city_map: CityMap,
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        Ok(Self {
+            city_map: CityMap::new(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(self.city_map.anti_nodes(false).len().into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(self.city_map.anti_nodes(true).len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn anti_nodes_example() {
+        assert_eq!(CityMap::new(EXAMPLE).unwrap().anti_nodes(false).len(), 14);
+    }
+
+    #[test]
+    fn resonant_anti_nodes_example() {
+        assert_eq!(CityMap::new(EXAMPLE).unwrap().anti_nodes(true).len(), 34);
+    }
+}
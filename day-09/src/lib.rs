@@ -0,0 +1,172 @@
+use aoc_common::{debug, trace, Answer, AocError, Params, Solution};
+
+#[derive(Debug)]
+pub struct File {
+    start: usize,
+    size: usize,
+}
+
+pub fn get_disk_repr(disk_map: &str) -> Result<(Vec<Option<usize>>, Vec<File>), String> {
+    let mut repr = vec![];
+    let mut files = vec![];
+
+    let mut is_file = [true, false].iter().cycle();
+    let mut i = 0;
+
+    for ch in disk_map.chars() {
+        let n = ch
+            .to_digit(10)
+            .ok_or_else(|| format!("Unknown block length {}", ch))? as usize;
+
+        let is_file = is_file.next().unwrap();
+
+        if *is_file {
+            files.push(File {
+                start: repr.len(),
+                size: n,
+            });
+            repr.extend([Some(i)].repeat(n));
+            i += 1;
+        } else {
+            repr.extend([None].repeat(n));
+        }
+    }
+
+    Ok((repr, files))
+}
+
+fn find_free_span(disk_repr: &[Option<usize>], start: usize) -> (usize, usize) {
+    let mut i = start;
+
+    while i < disk_repr.len() {
+        if disk_repr[i].is_none() {
+            let start = i;
+            while i < disk_repr.len() && disk_repr[i].is_none() {
+                i += 1;
+            }
+            return (start, i - start);
+        }
+        i += 1;
+    }
+
+    (disk_repr.len(), 0)
+}
+
+pub fn compact(disk_repr: &mut [Option<usize>], files: &[File]) {
+    for file in files.iter().rev() {
+        let (mut free_start, mut free_size) = find_free_span(disk_repr, 0);
+
+        while free_start + free_size < disk_repr.len() && free_size < file.size {
+            (free_start, free_size) = find_free_span(disk_repr, free_start + free_size);
+        }
+
+        if free_start != disk_repr.len() && free_size >= file.size && free_start < file.start {
+            let mut i = free_start;
+            let mut j = file.start;
+            debug!("free span: {} + {}", i, free_size);
+
+            while j < file.start + file.size {
+                trace!("swapping {} with {}", i, j);
+                disk_repr.swap(i, j);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+}
+
+pub fn compact_blocks(disk_repr: &mut [Option<usize>]) {
+    let mut free = 0;
+    let mut last = disk_repr.len();
+
+    while free < last {
+        if disk_repr[free].is_some() {
+            free += 1;
+        } else if disk_repr[last - 1].is_none() {
+            last -= 1;
+        } else {
+            disk_repr.swap(free, last - 1);
+            free += 1;
+            last -= 1;
+        }
+    }
+}
+
+pub fn checksum(disk_repr: &[Option<usize>]) -> usize {
+    disk_repr
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, block)| match block {
+            Some(id) => acc + i * id,
+            None => acc,
+        })
+}
+
+pub fn format_disk_repr(disk_repr: &[Option<usize>]) -> String {
+    disk_repr
+        .iter()
+        .map(|block| match block {
+            Some(id) => id.to_string(),
+            None => ".".to_string(),
+        })
+        .collect()
+}
+
+pub struct Day09 {
+    disk_repr: Vec<Option<usize>>,
+    files: Vec<File>,
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        let (disk_repr, files) = get_disk_repr(input)?;
+        Ok(Self { disk_repr, files })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let mut disk_repr = self.disk_repr.clone();
+        compact_blocks(&mut disk_repr);
+        Ok(checksum(&disk_repr).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let mut disk_repr = self.disk_repr.clone();
+        compact(&mut disk_repr, &self.files);
+        Ok(checksum(&disk_repr).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const TEST: &str = include_str!("../test.txt");
+
+    fn compact_blocks_checksum(disk_map: &str) -> usize {
+        let (mut disk_repr, _) = get_disk_repr(disk_map).unwrap();
+        compact_blocks(&mut disk_repr);
+        checksum(&disk_repr)
+    }
+
+    fn compact_files_checksum(disk_map: &str) -> usize {
+        let (mut disk_repr, files) = get_disk_repr(disk_map).unwrap();
+        compact(&mut disk_repr, &files);
+        checksum(&disk_repr)
+    }
+
+    #[test]
+    fn compact_blocks_example() {
+        assert_eq!(compact_blocks_checksum(EXAMPLE), 1928);
+        assert_eq!(compact_blocks_checksum(TEST), 60);
+    }
+
+    #[test]
+    fn compact_files_example() {
+        assert_eq!(compact_files_checksum(EXAMPLE), 2858);
+        assert_eq!(compact_files_checksum(TEST), 132);
+    }
+}
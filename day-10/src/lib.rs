@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, AocError, Grid, Params, Point, Solution};
+
+pub fn parse_map(input: &str) -> Result<Grid<i32>, String> {
+    Grid::parse_with(input, |ch| {
+        Ok::<_, String>(ch.to_digit(10).map_or(-1, |height| height as i32))
+    })
+}
+
+pub fn find_trailheads(map: &Grid<i32>) -> Vec<Point> {
+    map.find_all(&0).collect()
+}
+
+fn trace_trailhead(map: &Grid<i32>, ends: &mut Vec<Point>, curr: &Point, height: i32) {
+    if height == 9 {
+        ends.push(*curr);
+        return;
+    }
+
+    for next in map.neighbours4(*curr) {
+        if map[next] == height + 1 {
+            trace_trailhead(map, ends, &next, height + 1);
+        }
+    }
+}
+
+fn trailhead_ends(map: &Grid<i32>, start: &Point) -> Vec<Point> {
+    let mut ends: Vec<Point> = vec![];
+
+    trace_trailhead(map, &mut ends, start, 0);
+
+    ends
+}
+
+fn trailhead_score(map: &Grid<i32>, start: &Point) -> usize {
+    let ends: HashSet<Point> = trailhead_ends(map, start).into_iter().collect();
+    ends.len()
+}
+
+fn trailhead_rating(map: &Grid<i32>, start: &Point) -> usize {
+    trailhead_ends(map, start).len()
+}
+
+pub fn trailhead_scores(map: &Grid<i32>, trailheads: &[Point]) -> Vec<usize> {
+    trailheads
+        .iter()
+        .map(|trailhead| trailhead_score(map, trailhead))
+        .collect()
+}
+
+pub fn trailhead_ratings(map: &Grid<i32>, trailheads: &[Point]) -> Vec<usize> {
+    trailheads
+        .iter()
+        .map(|trailhead| trailhead_rating(map, trailhead))
+        .collect()
+}
+
+pub struct Day10 {
+    map: Grid<i32>,
+    trailheads: Vec<Point>,
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        let map = parse_map(input)?;
+        let trailheads = find_trailheads(&map);
+        Ok(Self { map, trailheads })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let scores = trailhead_scores(&self.map, &self.trailheads);
+        Ok(scores.iter().sum::<usize>().into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let ratings = trailhead_ratings(&self.map, &self.trailheads);
+        Ok(ratings.iter().sum::<usize>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const TEST: &str = include_str!("../test.txt");
+
+    fn scores_sum(input: &str) -> usize {
+        let map = parse_map(input).unwrap();
+        trailhead_scores(&map, &find_trailheads(&map)).iter().sum()
+    }
+
+    fn ratings_sum(input: &str) -> usize {
+        let map = parse_map(input).unwrap();
+        trailhead_ratings(&map, &find_trailheads(&map)).iter().sum()
+    }
+
+    #[test]
+    fn trailhead_scores_example() {
+        assert_eq!(scores_sum(EXAMPLE), 36);
+        assert_eq!(scores_sum(TEST), 2);
+    }
+
+    #[test]
+    fn trailhead_ratings_example() {
+        assert_eq!(ratings_sum(EXAMPLE), 81);
+        assert_eq!(ratings_sum(TEST), 2);
+    }
+}
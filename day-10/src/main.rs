@@ -1,99 +1,27 @@
-type Position = (i32, i32);
-
-fn read_input<P>(filename: P) -> std::io::Result<String>
-where
-    P: AsRef<std::path::Path>,
-{
-    std::fs::read_to_string(filename)
-}
-
-fn parse_map(input: &str) -> Vec<Vec<i32>> {
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|ch| {
-                    if ch.is_ascii_digit() {
-                        ch.to_digit(10).unwrap_or(0) as i32
-                    } else {
-                        -1
-                    }
-                })
-                .collect()
-        })
-        .collect()
-}
-
-fn find_trailheads(map: &[Vec<i32>]) -> Vec<Position> {
-    let mut trailheads = vec![];
-
-    for (i, row) in map.iter().enumerate() {
-        for (j, el) in row.iter().enumerate() {
-            if *el == 0 {
-                trailheads.push((i as i32, j as i32));
-            }
-        }
-    }
-
-    trailheads
-}
-
-const DIRECTIONS: [(i32, i32); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
-
-fn trace_trailhead(map: &[Vec<i32>], scores: &mut Vec<Position>, curr: &Position, height: i32) {
-    if height == 9 {
-        scores.push(*curr);
-        return;
-    }
-
-    let rows = map.len() as i32;
-    let cols = map[0].len() as i32;
-
-    for (di, dj) in DIRECTIONS {
-        let next_i = curr.0 + di;
-        let next_j = curr.1 + dj;
-
-        if next_i >= 0
-            && next_j >= 0
-            && next_i < rows
-            && next_j < cols
-            && map[next_i as usize][next_j as usize] == height + 1
-        {
-            trace_trailhead(map, scores, &(next_i, next_j), height + 1);
-        }
-    }
-}
-
-fn trailhead_score(map: &[Vec<i32>], start: &Position) -> usize {
-    let mut scores: Vec<Position> = vec![];
-
-    trace_trailhead(map, &mut scores, start, 0);
-
-    scores.len()
-}
-
-fn trailhead_scores(map: &[Vec<i32>], trailheads: &[Position]) -> Vec<usize> {
-    trailheads
-        .iter()
-        .map(|trailhead| trailhead_score(map, trailhead))
-        .collect()
-}
+use aoc_common::{debug, set_verbosity, Args, Solution};
+use day_10::{find_trailheads, parse_map, trailhead_ratings, trailhead_scores, Day10};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args: Vec<String> = std::env::args().collect();
-    let filename = &args[1];
+    let mut args = Args::from_env("[-v|-vv] <filename|-|--example>");
+    set_verbosity(args.verbosity());
+    let source = args.input(Day10::EXAMPLE)?;
+    args.finish()?;
 
-    let input = read_input(filename)?;
-    println!("{}", input);
+    let input = source.read()?;
+    debug!("{}", input);
 
-    let map = parse_map(&input);
+    let map = parse_map(&input)?;
 
     let trailheads = find_trailheads(&map);
-    println!("trailheads: {:?}", trailheads);
+    debug!("trailheads: {:?}", trailheads);
     let scores = trailhead_scores(&map, &trailheads);
-    println!("scores: {:?}", scores);
+    debug!("scores: {:?}", scores);
     let sum: usize = scores.iter().sum();
     println!("sum: {}", sum);
+    let ratings = trailhead_ratings(&map, &trailheads);
+    debug!("ratings: {:?}", ratings);
+    let ratings_sum: usize = ratings.iter().sum();
+    println!("ratings sum: {}", ratings_sum);
 
     Ok(())
 }
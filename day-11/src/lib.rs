@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, AocError, Params, Solution};
+
+pub fn parse_stones(input: &str) -> Result<HashMap<u64, u64>, String> {
+    let mut stones = HashMap::new();
+
+    for stone_str in input.split_whitespace() {
+        let stone = stone_str
+            .parse::<u64>()
+            .map_err(|_| format!("invalid stone '{}'", stone_str))?;
+        stones.entry(stone).and_modify(|e| *e += 1).or_insert(1);
+    }
+
+    Ok(stones)
+}
+
+fn split_number(n: u64) -> (u64, u64) {
+    let num_digits = ((n as f64).log10().floor() as usize).div_ceil(2);
+    let divisor = 10_u64.pow(num_digits as u32);
+    (n / divisor, n % divisor)
+}
+
+pub fn transform_stones(stones: &mut HashMap<u64, u64>) {
+    let mut new_stones = HashMap::with_capacity(stones.len() * 2);
+
+    for (&stone, &n) in stones.iter() {
+        if stone == 0 {
+            *new_stones.entry(1).or_insert(0) += n;
+        } else {
+            let num_digits = (stone as f64).log10().floor() as usize + 1;
+            if num_digits.is_multiple_of(2) {
+                let (l, r) = split_number(stone);
+                *new_stones.entry(l).or_insert(0) += n;
+                *new_stones.entry(r).or_insert(0) += n;
+            } else {
+                *new_stones.entry(stone * 2024).or_insert(0) += n;
+            }
+        }
+    }
+
+    *stones = new_stones
+}
+
+pub fn count_stones(stones: &HashMap<u64, u64>, blinks: usize) -> u64 {
+    let mut stones = stones.clone();
+
+    for _ in 0..blinks {
+        transform_stones(&mut stones);
+    }
+
+    stones.values().sum()
+}
+
+pub struct Day11 {
+    stones: HashMap<u64, u64>,
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        Ok(Self {
+            stones: parse_stones(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(count_stones(&self.stones, 25).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(count_stones(&self.stones, 75).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn count_stones_example() {
+        let stones = parse_stones(EXAMPLE).unwrap();
+        assert_eq!(count_stones(&stones, 6), 22);
+        assert_eq!(count_stones(&stones, 25), 55312);
+        assert_eq!(count_stones(&stones, 75), 65601038650482);
+    }
+
+    #[test]
+    fn invalid_stone() {
+        assert_eq!(parse_stones("125 x").unwrap_err(), "invalid stone 'x'");
+    }
+}
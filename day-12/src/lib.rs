@@ -0,0 +1,262 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, AocError, Direction, Grid, Params, Point, Solution};
+
+pub struct GardenMap {
+    map: Grid<char>,
+}
+
+impl GardenMap {
+    pub fn new(garden_map: &str) -> Result<Self, String> {
+        Ok(Self {
+            map: garden_map.parse()?,
+        })
+    }
+
+    fn get(&self, p: Point) -> Option<char> {
+        self.map.get(p).copied()
+    }
+}
+
+#[derive(Debug)]
+pub struct PlantRegion {
+    id: char,
+    points: HashSet<Point>,
+}
+
+impl PlantRegion {
+    fn new(id: char, points: HashSet<Point>) -> Self {
+        Self { id, points }
+    }
+
+    fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    fn perimeter(&self, garden_map: &GardenMap) -> usize {
+        let mut perimeter = 0;
+
+        for &p in self.points.iter() {
+            for dir in Direction::ALL {
+                perimeter += match garden_map.get(p + dir) {
+                    Some(ch) => {
+                        if ch != self.id {
+                            1
+                        } else {
+                            0
+                        }
+                    }
+                    None => 1,
+                };
+            }
+        }
+
+        perimeter
+    }
+
+    fn sides(&self, garden_map: &GardenMap) -> usize {
+        let mut rows: HashMap<i32, HashSet<Point>> = HashMap::new();
+        let mut cols: HashMap<i32, HashSet<Point>> = HashMap::new();
+
+        for &p in self.points.iter() {
+            rows.entry(p.row)
+                .and_modify(|e| {
+                    e.insert(p);
+                })
+                .or_insert_with(|| HashSet::from([p]));
+            cols.entry(p.col)
+                .and_modify(|e| {
+                    e.insert(p);
+                })
+                .or_insert_with(|| HashSet::from([p]));
+        }
+
+        let mut sides = 0;
+
+        for (_, row) in rows {
+            let mut top_sides = 0;
+            let mut top = false;
+            let mut bottom_sides = 0;
+            let mut bottom = false;
+
+            let mut row: Vec<Point> = row.iter().cloned().collect();
+
+            row.sort_by_key(|e| e.col);
+
+            for p in row {
+                let t = garden_map.get(p + Direction::Up);
+
+                if t.is_none() || t.unwrap() != self.id {
+                    if !top {
+                        top_sides += 1;
+                        top = true;
+                    }
+                } else {
+                    top = false;
+                }
+
+                let b = garden_map.get(p + Direction::Down);
+
+                if b.is_none() || b.unwrap() != self.id {
+                    if !bottom {
+                        bottom_sides += 1;
+                        bottom = true;
+                    }
+                } else {
+                    bottom = false;
+                }
+
+                if let Some(ch) = garden_map.get(p + Direction::Right) {
+                    if ch != self.id {
+                        top = false;
+                        bottom = false;
+                    }
+                }
+            }
+
+            sides += top_sides;
+            sides += bottom_sides;
+        }
+
+        for (_, col) in cols {
+            let mut left_sides = 0;
+            let mut left = false;
+            let mut right_sides = 0;
+            let mut right = false;
+
+            let mut col: Vec<Point> = col.iter().cloned().collect();
+
+            col.sort_by_key(|e| e.row);
+
+            for p in col {
+                let l = garden_map.get(p + Direction::Left);
+
+                if l.is_none() || l.unwrap() != self.id {
+                    if !left {
+                        left_sides += 1;
+                        left = true;
+                    }
+                } else {
+                    left = false;
+                }
+
+                let r = garden_map.get(p + Direction::Right);
+
+                if r.is_none() || r.unwrap() != self.id {
+                    if !right {
+                        right_sides += 1;
+                        right = true;
+                    }
+                } else {
+                    right = false;
+                }
+
+                if let Some(ch) = garden_map.get(p + Direction::Down) {
+                    if ch != self.id {
+                        left = false;
+                        right = false;
+                    }
+                }
+            }
+
+            sides += left_sides;
+            sides += right_sides;
+        }
+
+        sides
+    }
+}
+
+fn trace_region_points(garden_map: &GardenMap, points: &mut HashSet<Point>, id: char, curr: Point) {
+    points.insert(curr);
+
+    for next in garden_map.map.neighbours4(curr) {
+        if id == garden_map.map[next] && !points.contains(&next) {
+            trace_region_points(garden_map, points, id, next);
+        }
+    }
+}
+
+fn trace_region(garden_map: &GardenMap, id: char, start: Point) -> PlantRegion {
+    let mut points = HashSet::new();
+
+    trace_region_points(garden_map, &mut points, id, start);
+
+    PlantRegion::new(id, points)
+}
+
+pub fn find_regions(garden_map: &GardenMap) -> Vec<PlantRegion> {
+    let mut regions = vec![];
+    let mut visited: HashSet<Point> = HashSet::new();
+
+    for (pos, ch) in garden_map.map.iter() {
+        if !visited.contains(&pos) {
+            let region = trace_region(garden_map, *ch, pos);
+            region.points.iter().for_each(|p| {
+                visited.insert(*p);
+            });
+            regions.push(region);
+        }
+    }
+
+    regions
+}
+
+pub fn total_cost(regions: &[PlantRegion], garden_map: &GardenMap) -> usize {
+    regions
+        .iter()
+        .map(|r| r.area() * r.perimeter(garden_map))
+        .sum()
+}
+
+pub fn total_cost_with_discount(regions: &[PlantRegion], garden_map: &GardenMap) -> usize {
+    regions.iter().map(|r| r.area() * r.sides(garden_map)).sum()
+}
+
+pub struct Day12 {
+    garden_map: GardenMap,
+    regions: Vec<PlantRegion>,
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        let garden_map = GardenMap::new(input)?;
+        let regions = find_regions(&garden_map);
+        Ok(Self {
+            garden_map,
+            regions,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(total_cost(&self.regions, &self.garden_map).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(total_cost_with_discount(&self.regions, &self.garden_map).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn total_cost_example() {
+        let garden_map = GardenMap::new(EXAMPLE).unwrap();
+        let regions = find_regions(&garden_map);
+        assert_eq!(total_cost(&regions, &garden_map), 1930);
+    }
+
+    #[test]
+    fn total_cost_with_discount_example() {
+        let garden_map = GardenMap::new(EXAMPLE).unwrap();
+        let regions = find_regions(&garden_map);
+        assert_eq!(total_cost_with_discount(&regions, &garden_map), 1206);
+    }
+}
@@ -0,0 +1,315 @@
+use aoc_common::{Answer, AocError, Grid, Param, Params, Part, Point, Solution};
+
+#[derive(Debug, Clone)]
+struct Robot {
+    position: Point,
+    velocity: Point,
+}
+
+impl Robot {
+    fn x(&self) -> usize {
+        self.position.col as usize
+    }
+
+    fn y(&self) -> usize {
+        self.position.row as usize
+    }
+
+    fn advance(&mut self, rows: usize, cols: usize) {
+        let next = self.position + self.velocity;
+
+        self.position.row = next.row.rem_euclid(rows as i32);
+        self.position.col = next.col.rem_euclid(cols as i32);
+    }
+}
+
+impl std::str::FromStr for Robot {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (p_str, v_str) = s.split_once(" ").ok_or("Missing separator")?;
+
+        let (p_x_str, p_y_str) = p_str
+            .strip_prefix("p=")
+            .ok_or("Missing position prefix")?
+            .split_once(",")
+            .ok_or("Missing position separator")?;
+        let p_x = p_x_str
+            .parse::<i32>()
+            .map_err(|_| "Invalid x position".to_string())?;
+        let p_y = p_y_str
+            .parse::<i32>()
+            .map_err(|_| "Invalid y position".to_string())?;
+
+        let (v_x_str, v_y_str) = v_str
+            .strip_prefix("v=")
+            .ok_or("Missing velocity prefix")?
+            .split_once(",")
+            .ok_or("Missing velocity separator")?;
+        let v_x = v_x_str
+            .parse::<i32>()
+            .map_err(|_| "Invalid x velocity".to_string())?;
+        let v_y = v_y_str
+            .parse::<i32>()
+            .map_err(|_| "Invalid y velocity".to_string())?;
+
+        // the input is in x,y order
+        Ok(Self {
+            position: Point::new(p_y, p_x),
+            velocity: Point::new(v_y, v_x),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct Matrix {
+    robots: Vec<Robot>,
+    map: Grid<usize>,
+    visual_map: Grid<char>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Matrix {
+    pub fn new(input: &str, rows: usize, cols: usize) -> Result<Self, String> {
+        if rows == 0 || cols == 0 {
+            return Err(format!("Area must be at least 1x1, got {}x{}", cols, rows));
+        }
+
+        let robots: Vec<Robot> = input
+            .lines()
+            .map(|l| l.parse::<Robot>())
+            .collect::<Result<_, _>>()?;
+        let area = Grid::new(rows, cols, ());
+        if let Some(i) = robots.iter().position(|r| !area.contains(r.position)) {
+            return Err(format!(
+                "line {}: robot {},{} is outside of the {}x{} area",
+                i + 1,
+                robots[i].position.col,
+                robots[i].position.row,
+                cols,
+                rows
+            ));
+        }
+        let map = Matrix::get_map(&robots, rows, cols);
+        let visual_map = Matrix::get_visual_map(&map);
+        Ok(Self {
+            robots,
+            rows,
+            cols,
+            map,
+            visual_map,
+        })
+    }
+
+    fn get_map(robots: &[Robot], rows: usize, cols: usize) -> Grid<usize> {
+        let mut map = Grid::new(rows, cols, 0);
+        for robot in robots.iter() {
+            map[robot.position] += 1;
+        }
+        map
+    }
+
+    fn get_visual_map(map: &Grid<usize>) -> Grid<char> {
+        map.map(|n| if *n > 0 { '#' } else { ' ' })
+    }
+
+    pub fn advance_robots(&mut self) {
+        for robot in self.robots.iter_mut() {
+            let old = robot.position;
+            robot.advance(self.rows, self.cols);
+            let new = robot.position;
+
+            self.map[old] -= 1;
+            if self.map[old] == 0 {
+                self.visual_map[old] = ' ';
+            }
+            self.map[new] += 1;
+            if self.map[new] > 0 {
+                self.visual_map[new] = '#';
+            }
+        }
+    }
+
+    pub fn safety_factor(&self) -> usize {
+        let (mid_x, mid_y) = (self.cols / 2, self.rows / 2);
+        let mut quadrants = [0; 4];
+
+        for robot in self.robots.iter() {
+            let (x, y) = (robot.x(), robot.y());
+            if x == mid_x || y == mid_y {
+                continue;
+            }
+
+            let quadrant = usize::from(x > mid_x) + 2 * usize::from(y > mid_y);
+            quadrants[quadrant] += 1;
+        }
+
+        quadrants.iter().product()
+    }
+
+    pub fn find_easter_egg(&mut self) -> Option<usize> {
+        let mut cnt = 0;
+
+        loop {
+            self.advance_robots();
+            cnt += 1;
+            if self.has_easter_egg() {
+                return Some(cnt);
+            }
+
+            if cnt > MAX_ITERATIONS {
+                return None;
+            }
+        }
+    }
+
+    fn has_easter_egg(&self) -> bool {
+        let mut j = 0;
+
+        for row in 0..self.visual_map.rows() {
+            if j == PATTERN.len() {
+                return true;
+            }
+
+            let line_str: String = self.visual_map.row(row).iter().collect();
+            if line_str.contains(PATTERN[j]) {
+                j += 1;
+            }
+        }
+
+        j == PATTERN.len()
+    }
+}
+
+impl std::fmt::Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.visual_map)
+    }
+}
+
+const PATTERN: [&str; 33] = [
+    "###############################",
+    "#                             #",
+    "#                             #",
+    "#                             #",
+    "#                             #",
+    "#              #              #",
+    "#             ###             #",
+    "#            #####            #",
+    "#           #######           #",
+    "#          #########          #",
+    "#            #####            #",
+    "#           #######           #",
+    "#          #########          #",
+    "#         ###########         #",
+    "#        #############        #",
+    "#          #########          #",
+    "#         ###########         #",
+    "#        #############        #",
+    "#       ###############       #",
+    "#      #################      #",
+    "#        #############        #",
+    "#       ###############       #",
+    "#      #################      #",
+    "#     ###################     #",
+    "#    #####################    #",
+    "#             ###             #",
+    "#             ###             #",
+    "#             ###             #",
+    "#                             #",
+    "#                             #",
+    "#                             #",
+    "#                             #",
+    "###############################",
+];
+
+const MAX_ITERATIONS: usize = 20000;
+
+const SECONDS: usize = 100;
+
+pub struct Day14 {
+    matrix: Matrix,
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "cols",
+            default: "101",
+            example: "11",
+        },
+        Param {
+            name: "rows",
+            default: "103",
+            example: "7",
+        },
+    ];
+
+    fn parse(input: &str, params: &Params) -> Result<Self, AocError> {
+        Ok(Self {
+            matrix: Matrix::new(input, params.get("rows")?, params.get("cols")?)?,
+        })
+    }
+
+    // the example robots never draw the tree
+    fn example(part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => Some(Self::EXAMPLE),
+            Part::Two => None,
+        }
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let mut matrix = self.matrix.clone();
+        for _ in 0..SECONDS {
+            matrix.advance_robots();
+        }
+        Ok(matrix.safety_factor().into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let mut matrix = self.matrix.clone();
+        matrix
+            .find_easter_egg()
+            .map(Answer::from)
+            .ok_or_else(|| AocError::NoSolution("Couldn't find easter egg".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const TEST: &str = include_str!("../test.txt");
+
+    #[test]
+    fn advance_robot_wraps_around() {
+        let mut matrix = Matrix::new(TEST, 7, 11).unwrap();
+        for _ in 0..5 {
+            matrix.advance_robots();
+        }
+        assert_eq!(matrix.map[Point::new(3, 1)], 1);
+    }
+
+    #[test]
+    fn safety_factor_example() {
+        let mut matrix = Matrix::new(EXAMPLE, 7, 11).unwrap();
+        for _ in 0..SECONDS {
+            matrix.advance_robots();
+        }
+        assert_eq!(matrix.safety_factor(), 12);
+    }
+
+    #[test]
+    fn robots_outside_the_area() {
+        assert_eq!(
+            Matrix::new(EXAMPLE, 3, 3).err().unwrap(),
+            "line 1: robot 0,4 is outside of the 3x3 area"
+        );
+        assert!(Matrix::new(EXAMPLE, 0, 11).is_err());
+    }
+}
@@ -0,0 +1,421 @@
+use std::collections::HashSet;
+
+use aoc_common::{trace, Answer, AocError, Direction, Grid, Params, Point, Solution};
+
+struct BoxPosition {
+    l: Point,
+    r: Point,
+}
+
+#[derive(Debug)]
+pub struct Warehouse {
+    robot: Point,
+    boxes_l: HashSet<Point>,
+    boxes_r: HashSet<Point>,
+    walls: Grid<bool>,
+}
+
+impl Warehouse {
+    fn move_robot(&mut self, dir: Direction) {
+        let next_pos = self.robot + dir;
+
+        if self.boxes_l.contains(&next_pos) {
+            let box_pos = BoxPosition {
+                l: next_pos,
+                r: next_pos + Direction::Right,
+            };
+            if self.can_move_box(&box_pos, dir) {
+                self.move_box(&box_pos, dir);
+                self.robot = next_pos;
+            }
+        }
+
+        if self.boxes_r.contains(&next_pos) {
+            let box_pos = BoxPosition {
+                l: next_pos + Direction::Left,
+                r: next_pos,
+            };
+            if self.can_move_box(&box_pos, dir) {
+                self.move_box(&box_pos, dir);
+                self.robot = next_pos;
+            }
+        }
+
+        if !self.boxes_l.contains(&next_pos)
+            && !self.boxes_r.contains(&next_pos)
+            && !self.walls[next_pos]
+        {
+            self.robot = next_pos;
+        }
+    }
+
+    fn can_move_box(&self, box_pos: &BoxPosition, dir: Direction) -> bool {
+        let next_box_pos = BoxPosition {
+            l: box_pos.l + dir,
+            r: box_pos.r + dir,
+        };
+
+        if dir == Direction::Left {
+            if self.walls[next_box_pos.l] {
+                return false;
+            }
+
+            if self.boxes_r.contains(&next_box_pos.l) {
+                return self.can_move_box(
+                    &BoxPosition {
+                        l: next_box_pos.l + Direction::Left,
+                        r: next_box_pos.l,
+                    },
+                    dir,
+                );
+            }
+
+            true
+        } else if dir == Direction::Right {
+            if self.walls[next_box_pos.r] {
+                return false;
+            }
+
+            if self.boxes_l.contains(&next_box_pos.r) {
+                return self.can_move_box(
+                    &BoxPosition {
+                        l: next_box_pos.r,
+                        r: next_box_pos.r + Direction::Right,
+                    },
+                    dir,
+                );
+            }
+
+            true
+        } else {
+            if self.walls[next_box_pos.l] || self.walls[next_box_pos.r] {
+                return false;
+            }
+
+            if self.boxes_l.contains(&next_box_pos.l) && self.boxes_r.contains(&next_box_pos.r) {
+                return self.can_move_box(&next_box_pos, dir);
+            }
+
+            if self.boxes_l.contains(&next_box_pos.r) && !self.boxes_r.contains(&next_box_pos.l) {
+                return self.can_move_box(
+                    &BoxPosition {
+                        l: next_box_pos.r,
+                        r: next_box_pos.r + Direction::Right,
+                    },
+                    dir,
+                );
+            }
+
+            if !self.boxes_l.contains(&next_box_pos.r) && self.boxes_r.contains(&next_box_pos.l) {
+                return self.can_move_box(
+                    &BoxPosition {
+                        l: next_box_pos.l + Direction::Left,
+                        r: next_box_pos.l,
+                    },
+                    dir,
+                );
+            }
+
+            if self.boxes_l.contains(&next_box_pos.r) && !self.boxes_r.contains(&next_box_pos.l) {
+                return self.can_move_box(
+                    &BoxPosition {
+                        l: next_box_pos.r,
+                        r: next_box_pos.r + Direction::Right,
+                    },
+                    dir,
+                );
+            }
+
+            if self.boxes_l.contains(&next_box_pos.r) && self.boxes_r.contains(&next_box_pos.l) {
+                return self.can_move_box(
+                    &BoxPosition {
+                        l: next_box_pos.l + Direction::Left,
+                        r: next_box_pos.l,
+                    },
+                    dir,
+                ) && self.can_move_box(
+                    &BoxPosition {
+                        l: next_box_pos.r,
+                        r: next_box_pos.r + Direction::Right,
+                    },
+                    dir,
+                );
+            }
+
+            true
+        }
+    }
+
+    fn move_box(&mut self, box_pos: &BoxPosition, dir: Direction) {
+        let next_box_pos = BoxPosition {
+            l: box_pos.l + dir,
+            r: box_pos.r + dir,
+        };
+
+        if dir == Direction::Left {
+            if self.boxes_r.contains(&next_box_pos.l) {
+                self.move_box(
+                    &BoxPosition {
+                        l: next_box_pos.l + Direction::Left,
+                        r: next_box_pos.l,
+                    },
+                    dir,
+                );
+            }
+        } else if dir == Direction::Right {
+            if self.boxes_l.contains(&next_box_pos.r) {
+                self.move_box(
+                    &BoxPosition {
+                        l: next_box_pos.r,
+                        r: next_box_pos.r + Direction::Right,
+                    },
+                    dir,
+                );
+            }
+        } else {
+            if self.boxes_l.contains(&next_box_pos.l) && self.boxes_r.contains(&next_box_pos.r) {
+                self.move_box(&next_box_pos, dir);
+            }
+
+            if self.boxes_l.contains(&next_box_pos.r) && !self.boxes_r.contains(&next_box_pos.l) {
+                self.move_box(
+                    &BoxPosition {
+                        l: next_box_pos.r,
+                        r: next_box_pos.r + Direction::Right,
+                    },
+                    dir,
+                );
+            }
+
+            if !self.boxes_l.contains(&next_box_pos.r) && self.boxes_r.contains(&next_box_pos.l) {
+                self.move_box(
+                    &BoxPosition {
+                        l: next_box_pos.l + Direction::Left,
+                        r: next_box_pos.l,
+                    },
+                    dir,
+                );
+            }
+
+            if self.boxes_l.contains(&next_box_pos.r) && !self.boxes_r.contains(&next_box_pos.l) {
+                self.move_box(
+                    &BoxPosition {
+                        l: next_box_pos.r,
+                        r: next_box_pos.r + Direction::Right,
+                    },
+                    dir,
+                );
+            }
+
+            if self.boxes_l.contains(&next_box_pos.r) && self.boxes_r.contains(&next_box_pos.l) {
+                self.move_box(
+                    &BoxPosition {
+                        l: next_box_pos.l + Direction::Left,
+                        r: next_box_pos.l,
+                    },
+                    dir,
+                );
+                self.move_box(
+                    &BoxPosition {
+                        l: next_box_pos.r,
+                        r: next_box_pos.r + Direction::Right,
+                    },
+                    dir,
+                );
+            }
+        }
+
+        self.boxes_l.remove(&box_pos.l);
+        self.boxes_r.remove(&box_pos.r);
+
+        self.boxes_l.insert(next_box_pos.l);
+        self.boxes_r.insert(next_box_pos.r);
+    }
+
+    pub fn gps(&self) -> i32 {
+        self.boxes_l.iter().map(|p| p.row * 100 + p.col).sum()
+    }
+}
+
+impl std::str::FromStr for Warehouse {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let wide = s
+            .replace("#", "##")
+            .replace("O", "[]")
+            .replace(".", "..")
+            .replace("@", "@.");
+        let map: Grid<char> = wide.parse()?;
+
+        let robot = map.find(&'@').ok_or("Missing robot")?;
+        let boxes_l = map.find_all(&'[').collect();
+        let boxes_r = map.find_all(&']').collect();
+        let walls = map.map(|ch| *ch == '#');
+
+        Ok(Self {
+            robot,
+            boxes_l,
+            boxes_r,
+            walls,
+        })
+    }
+}
+
+impl std::fmt::Display for Warehouse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut map = self.walls.map(|wall| if *wall { '#' } else { '.' });
+        self.boxes_l.iter().for_each(|&p| map[p] = '[');
+        self.boxes_r.iter().for_each(|&p| map[p] = ']');
+        map[self.robot] = '@';
+        write!(f, "{}", map)
+    }
+}
+
+pub fn parse_moves(moves: &str) -> Result<Vec<Direction>, String> {
+    moves
+        .lines()
+        .enumerate()
+        .flat_map(|(i, line)| {
+            line.chars().map(move |ch| {
+                Direction::from_arrow(ch)
+                    .ok_or_else(|| format!("line {}: invalid move '{}'", i + 1, ch))
+            })
+        })
+        .collect()
+}
+
+pub fn move_robot(warehouse: &mut Warehouse, moves: &[Direction]) {
+    for &dir in moves {
+        trace!("{}", dir.arrow());
+        warehouse.move_robot(dir);
+    }
+}
+
+#[derive(Debug)]
+pub struct NarrowWarehouse {
+    map: Grid<char>,
+    robot: Point,
+}
+
+impl NarrowWarehouse {
+    fn move_robot(&mut self, dir: Direction) {
+        let next_pos = self.robot + dir;
+
+        // find the first cell after the row of boxes in front of the robot
+        let mut end = next_pos;
+        while self.map[end] == 'O' {
+            end += dir;
+        }
+
+        if self.map[end] == '#' {
+            return;
+        }
+
+        if end != next_pos {
+            self.map[end] = 'O';
+            self.map[next_pos] = '.';
+        }
+        self.robot = next_pos;
+    }
+
+    pub fn gps(&self) -> i32 {
+        self.map.find_all(&'O').map(|p| p.row * 100 + p.col).sum()
+    }
+}
+
+impl std::str::FromStr for NarrowWarehouse {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map: Grid<char> = s.parse()?;
+        let robot = map.find(&'@').ok_or("Missing robot")?;
+        map[robot] = '.';
+
+        Ok(Self { map, robot })
+    }
+}
+
+pub fn move_narrow_robot(warehouse: &mut NarrowWarehouse, moves: &[Direction]) {
+    for &dir in moves {
+        warehouse.move_robot(dir);
+    }
+}
+
+fn split_input(input: &str) -> Result<(&str, &str), String> {
+    input
+        .split_once("\n\n")
+        .ok_or("Missing map/moves delimiter".to_string())
+}
+
+pub struct Day15 {
+    map: String,
+    moves: Vec<Direction>,
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        let (map, moves) = split_input(input)?;
+        Ok(Self {
+            map: map.to_string(),
+            moves: parse_moves(moves)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let mut warehouse = self.map.parse::<NarrowWarehouse>()?;
+        move_narrow_robot(&mut warehouse, &self.moves);
+        Ok(warehouse.gps().into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let mut warehouse = self.map.parse::<Warehouse>()?;
+        move_robot(&mut warehouse, &self.moves);
+        Ok(warehouse.gps().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const TEST: &str = include_str!("../test.txt");
+
+    fn narrow_gps(input: &str) -> i32 {
+        let (map, moves) = split_input(input).unwrap();
+        let mut warehouse = map.parse::<NarrowWarehouse>().unwrap();
+        move_narrow_robot(&mut warehouse, &parse_moves(moves).unwrap());
+        warehouse.gps()
+    }
+
+    fn wide_gps(input: &str) -> i32 {
+        let (map, moves) = split_input(input).unwrap();
+        let mut warehouse = map.parse::<Warehouse>().unwrap();
+        move_robot(&mut warehouse, &parse_moves(moves).unwrap());
+        warehouse.gps()
+    }
+
+    #[test]
+    fn narrow_warehouse_example() {
+        assert_eq!(narrow_gps(EXAMPLE), 10092);
+        assert_eq!(narrow_gps(TEST), 2028);
+    }
+
+    #[test]
+    fn wide_warehouse_example() {
+        assert_eq!(wide_gps(EXAMPLE), 9021);
+        assert_eq!(wide_gps(TEST), 1751);
+    }
+
+    #[test]
+    fn invalid_move() {
+        assert_eq!(
+            parse_moves("<^\n>x").unwrap_err(),
+            "line 2: invalid move 'x'"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use aoc_common::{search, Answer, AocError, Direction, Grid, Params, Point, Solution};
+
+pub struct Maze {
+    map: Grid<char>,
+    start: Point,
+    end: Point,
+}
+
+impl Maze {
+    pub fn new(input: &str) -> Result<Self, String> {
+        let map: Grid<char> = input.parse()?;
+        let start = map.find(&'S').ok_or("Missing start")?;
+        let end = map.find(&'E').ok_or("Missing end")?;
+
+        Ok(Self { map, start, end })
+    }
+
+    fn neighbors(
+        &self,
+        curr: Point,
+        dir: Direction,
+    ) -> impl Iterator<Item = (Point, Direction)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter(move |d| *d != dir.opposite())
+            .map(move |d| (curr + d, d))
+            .filter(|(next, _)| self.map.get(*next).is_some_and(|ch| *ch != '#'))
+    }
+
+    /// Lowest score and the tiles on any path with that score.
+    pub fn shortest_paths(&self) -> Option<(usize, HashSet<Point>)> {
+        let paths = search::dijkstra_all(
+            (self.start, Direction::Right),
+            |&(curr, dir)| {
+                self.neighbors(curr, dir).map(move |(next, next_dir)| {
+                    let cost = if next_dir != dir { 1001 } else { 1 };
+                    ((next, next_dir), cost)
+                })
+            },
+            |&(curr, _)| curr == self.end,
+        )?;
+
+        let tiles = paths.states().into_iter().map(|(p, _)| p).collect();
+        Some((paths.cost, tiles))
+    }
+}
+
+pub struct Day16 {
+    maze: Maze,
+}
+
+impl Day16 {
+    fn shortest_paths(&self) -> Result<(usize, HashSet<Point>), AocError> {
+        self.maze
+            .shortest_paths()
+            .ok_or_else(|| AocError::NoSolution("End is unreachable".to_string()))
+    }
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        Ok(Self {
+            maze: Maze::new(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let (cost, _) = self.shortest_paths()?;
+        Ok(cost.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let (_, unique) = self.shortest_paths()?;
+        Ok(unique.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn shortest_paths_example() {
+        let (cost, unique) = Maze::new(EXAMPLE).unwrap().shortest_paths().unwrap();
+        assert_eq!((cost, unique.len()), (7036, 45));
+    }
+
+    #[test]
+    fn shortest_paths_example2() {
+        let (cost, unique) = Maze::new(EXAMPLE2).unwrap().shortest_paths().unwrap();
+        assert_eq!((cost, unique.len()), (11048, 64));
+    }
+}
@@ -0,0 +1,229 @@
+use aoc_common::{Answer, AocError, Params, Part, Solution};
+
+#[derive(Debug)]
+struct Computer {
+    registers: [i64; 3],
+    program: Vec<i64>,
+    pointer: usize,
+    output: Vec<i64>,
+}
+
+impl Computer {
+    fn new(registers: [i64; 3], program: Vec<i64>) -> Self {
+        Self {
+            registers,
+            program,
+            pointer: 0,
+            output: vec![],
+        }
+    }
+
+    fn literal_operand(&self) -> i64 {
+        self.program[self.pointer + 1]
+    }
+
+    fn combo_operand(&self) -> i64 {
+        let operand = self.program[self.pointer + 1];
+        match operand {
+            0..=3 => operand,
+            4 => self.registers[0],
+            5 => self.registers[1],
+            6 => self.registers[2],
+            7 => panic!("Invalid combo operand"),
+            _ => unreachable!(),
+        }
+    }
+
+    fn adv(&mut self) {
+        let lhs = self.registers[0];
+        let rhs = 2_i64.pow(self.combo_operand() as u32);
+        self.registers[0] = lhs / rhs;
+        self.pointer += 2;
+    }
+
+    fn bxl(&mut self) {
+        let lhs = self.registers[1];
+        let rhs = self.literal_operand();
+        self.registers[1] = lhs ^ rhs;
+        self.pointer += 2;
+    }
+
+    fn bst(&mut self) {
+        let lhs = self.combo_operand();
+        self.registers[1] = lhs % 8;
+        self.pointer += 2;
+    }
+
+    fn jnz(&mut self) {
+        if self.registers[0] == 0 {
+            self.pointer += 2;
+            return;
+        }
+
+        self.pointer = self.literal_operand() as usize;
+    }
+
+    fn bxc(&mut self) {
+        self.registers[1] ^= self.registers[2];
+        self.pointer += 2;
+    }
+
+    fn out(&mut self) {
+        let lhs = self.combo_operand();
+        self.output.push(lhs % 8);
+        self.pointer += 2;
+    }
+
+    fn bdv(&mut self) {
+        let lhs = self.registers[0];
+        let rhs = 2_i64.pow(self.combo_operand() as u32);
+        self.registers[1] = lhs / rhs;
+        self.pointer += 2;
+    }
+
+    fn cdv(&mut self) {
+        let lhs = self.registers[0];
+        let rhs = 2_i64.pow(self.combo_operand() as u32);
+        self.registers[2] = lhs / rhs;
+        self.pointer += 2;
+    }
+
+    fn run(&mut self) {
+        self.pointer = 0;
+        self.output = vec![];
+        while self.pointer < self.program.len() {
+            let opcode = self.program[self.pointer];
+            match opcode {
+                0 => self.adv(),
+                1 => self.bxl(),
+                2 => self.bst(),
+                3 => self.jnz(),
+                4 => self.bxc(),
+                5 => self.out(),
+                6 => self.bdv(),
+                7 => self.cdv(),
+                _ => unreachable!(),
+            };
+        }
+    }
+
+    fn output(&self) -> String {
+        self.output
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+pub fn parse_input(s: &str) -> Result<([i64; 3], Vec<i64>), String> {
+    let (registers_str, program_str) = s
+        .split_once("\n\n")
+        .ok_or("Missing registers/program delimiter")?;
+
+    let registers = registers_str
+        .lines()
+        .filter_map(|line| {
+            let (_, val) = line.split_once(": ")?;
+            val.parse::<i64>().ok()
+        })
+        .collect::<Vec<_>>();
+
+    let registers: [i64; 3] = registers
+        .try_into()
+        .map_err(|_| "Invalid registers length".to_string())?;
+
+    let program = program_str
+        .split_once(": ")
+        .ok_or("Missing program delimiter")?
+        .1
+        .split(",")
+        .filter_map(|v| v.parse::<i64>().ok())
+        .collect::<Vec<_>>();
+
+    Ok((registers, program))
+}
+
+pub fn program_output(registers: [i64; 3], program: &[i64]) -> String {
+    let mut computer = Computer::new(registers, program.to_vec());
+    computer.run();
+    computer.output()
+}
+
+pub fn lowest_quine_a(registers: [i64; 3], program: &[i64]) -> Option<i64> {
+    let mut valid = vec![0];
+
+    for &out in program.iter().rev() {
+        let mut next = Vec::new();
+
+        for v in valid {
+            for n in 0..8 {
+                let a = (v << 3) | n;
+                let mut computer = Computer::new([a, registers[1], registers[2]], program.to_vec());
+                computer.run();
+                let result = computer.output[0];
+                if result == out {
+                    next.push(a);
+                }
+            }
+        }
+
+        valid = next;
+    }
+
+    valid.into_iter().min()
+}
+
+pub struct Day17 {
+    registers: [i64; 3],
+    program: Vec<i64>,
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        let (registers, program) = parse_input(input)?;
+        Ok(Self { registers, program })
+    }
+
+    // the first example never outputs itself
+    fn example(part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => Some(Self::EXAMPLE),
+            Part::Two => Some(include_str!("../example2.txt")),
+        }
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(program_output(self.registers, &self.program).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let a = lowest_quine_a(self.registers, &self.program).ok_or(AocError::NoSolution(
+            "No value of A outputs the program".to_string(),
+        ))?;
+        Ok(a.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn program_output_example() {
+        let (registers, program) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(program_output(registers, &program), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn lowest_quine_a_example() {
+        let (registers, program) = parse_input(EXAMPLE2).unwrap();
+        assert_eq!(lowest_quine_a(registers, &program), Some(117440));
+    }
+}
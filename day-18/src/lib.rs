@@ -0,0 +1,208 @@
+use aoc_common::{search, Answer, AocError, Grid, Param, Params, Point, Solution};
+
+struct Memory {
+    corrupted: Grid<bool>,
+}
+
+impl Memory {
+    fn new(bytes: &[Point], size: i32) -> Self {
+        let mut memory = Self {
+            corrupted: Grid::new(size as usize, size as usize, false),
+        };
+        memory.update_corrupted(bytes);
+        memory
+    }
+
+    fn update_corrupted(&mut self, bytes: &[Point]) {
+        let (rows, cols) = (self.corrupted.rows(), self.corrupted.cols());
+        self.corrupted = Grid::new(rows, cols, false);
+
+        for &pos in bytes {
+            self.corrupted[pos] = true;
+        }
+    }
+
+    fn shortest_path(&self) -> Option<usize> {
+        let end = Point::new(
+            self.corrupted.rows() as i32 - 1,
+            self.corrupted.cols() as i32 - 1,
+        );
+
+        let path = search::astar(
+            Point::ORIGIN,
+            |&curr| {
+                self.corrupted
+                    .neighbours4(curr)
+                    .filter(|&next| !self.corrupted[next])
+                    .map(|next| (next, 1))
+            },
+            |p| p.manhattan(end) as usize,
+            |&p| p == end,
+        )?;
+        Some(path.cost)
+    }
+}
+
+impl std::fmt::Display for Memory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let map = self
+            .corrupted
+            .map(|corrupted| if *corrupted { '#' } else { '.' });
+        write!(f, "{}", map)
+    }
+}
+
+/// `X,Y` per line, each inside the `size`×`size` memory, and at least `bytes` of them.
+pub fn byte_positions(input: &str, bytes: usize, size: i32) -> Result<Vec<Point>, String> {
+    if size < 1 {
+        return Err(format!("Memory size must be at least 1, got {}", size));
+    }
+
+    let byte_positions = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let error = |message: String| format!("line {}: {}", i + 1, message);
+            let (col_str, row_str) = line
+                .split_once(",")
+                .ok_or_else(|| error(format!("expected X,Y, found '{}'", line)))?;
+            let row = row_str
+                .parse::<i32>()
+                .map_err(|_| error(format!("invalid Y '{}'", row_str)))?;
+            let col = col_str
+                .parse::<i32>()
+                .map_err(|_| error(format!("invalid X '{}'", col_str)))?;
+            if !(0..size).contains(&row) || !(0..size).contains(&col) {
+                return Err(error(format!(
+                    "byte {},{} is outside of the {}x{} memory",
+                    col, row, size, size
+                )));
+            }
+            Ok(Point::new(row, col))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if bytes > byte_positions.len() {
+        return Err(format!(
+            "Asked for {} bytes, but only {} fall",
+            bytes,
+            byte_positions.len()
+        ));
+    }
+
+    Ok(byte_positions)
+}
+
+pub fn shortest_path(byte_positions: &[Point], bytes: usize, size: i32) -> Option<usize> {
+    let memory = Memory::new(&byte_positions[0..bytes], size);
+    memory.shortest_path()
+}
+
+/// `None` when the exit stays reachable after every byte has fallen.
+pub fn first_unreachable_byte(byte_positions: &[Point], bytes: usize, size: i32) -> Option<Point> {
+    let mut memory = Memory::new(byte_positions, size);
+    if memory.shortest_path().is_some() {
+        return None;
+    }
+
+    // the exit is reachable with `bad` bytes fallen and unreachable with `good`
+    let mut bad = bytes;
+    let mut good = byte_positions.len();
+    memory.update_corrupted(&byte_positions[0..bad]);
+    if memory.shortest_path().is_none() {
+        bad = 0;
+    }
+
+    while good - bad > 1 {
+        let middle = (good + bad) / 2;
+        memory.update_corrupted(&byte_positions[0..middle]);
+        if memory.shortest_path().is_none() {
+            good = middle;
+        } else {
+            bad = middle;
+        }
+    }
+
+    Some(byte_positions[bad])
+}
+
+pub struct Day18 {
+    byte_positions: Vec<Point>,
+    size: i32,
+    bytes: usize,
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "size",
+            default: "71",
+            example: "7",
+        },
+        Param {
+            name: "bytes",
+            default: "1024",
+            example: "12",
+        },
+    ];
+
+    fn parse(input: &str, params: &Params) -> Result<Self, AocError> {
+        let size = params.get("size")?;
+        let bytes = params.get("bytes")?;
+        Ok(Self {
+            byte_positions: byte_positions(input, bytes, size)?,
+            size,
+            bytes,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        shortest_path(&self.byte_positions, self.bytes, self.size)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::NoSolution("Exit is unreachable".to_string()))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let byte = first_unreachable_byte(&self.byte_positions, self.bytes, self.size)
+            .ok_or_else(|| AocError::NoSolution("Exit is never cut off".to_string()))?;
+        Ok(format!("{},{}", byte.col, byte.row).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn shortest_path_example() {
+        let byte_positions = byte_positions(EXAMPLE, 12, 7).unwrap();
+        assert_eq!(shortest_path(&byte_positions, 12, 7), Some(22));
+    }
+
+    #[test]
+    fn first_unreachable_byte_example() {
+        let byte_positions = byte_positions(EXAMPLE, 12, 7).unwrap();
+        assert_eq!(
+            first_unreachable_byte(&byte_positions, 12, 7),
+            Some(Point::new(1, 6))
+        );
+        assert_eq!(first_unreachable_byte(&byte_positions[..20], 12, 7), None);
+    }
+
+    #[test]
+    fn params_are_validated() {
+        assert_eq!(
+            byte_positions(EXAMPLE, 500, 7),
+            Err("Asked for 500 bytes, but only 25 fall".to_string())
+        );
+        assert_eq!(
+            byte_positions(EXAMPLE, 12, 6),
+            Err("line 6: byte 6,3 is outside of the 6x6 memory".to_string())
+        );
+        assert!(byte_positions("1,x", 0, 7).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use aoc_common::{Answer, AocError, Params, Solution};
+
+pub struct Combinator<'a> {
+    patterns: Vec<Cow<'a, str>>,
+    cache: HashMap<&'a str, usize>,
+}
+
+impl<'a> Combinator<'a> {
+    pub fn new(patterns: Vec<String>) -> Self {
+        let patterns = patterns.into_iter().map(Cow::Owned).collect();
+        let cache = HashMap::new();
+        Self { patterns, cache }
+    }
+
+    fn count_possible(&mut self, design: &'a str) -> usize {
+        if design.is_empty() {
+            return 1;
+        }
+
+        if let Some(&possible) = self.cache.get(design) {
+            return possible;
+        }
+
+        let patterns = self.patterns.clone();
+
+        let mut count_possible = 0;
+        for pat in &patterns {
+            if design.starts_with(pat.as_ref()) {
+                let remaining = &design[pat.len()..];
+                count_possible += self.count_possible(remaining);
+            }
+        }
+
+        self.cache.insert(design, count_possible);
+        count_possible
+    }
+
+    pub fn count_possible_designs(&mut self, designs: &[&'a str]) -> usize {
+        designs
+            .iter()
+            .filter(|design| self.count_possible(design) != 0)
+            .count()
+    }
+
+    pub fn count_possible_design_ways(&mut self, designs: &[&'a str]) -> usize {
+        designs
+            .iter()
+            .map(|design| self.count_possible(design))
+            .sum()
+    }
+}
+
+pub fn parse_input(input: &str) -> (Vec<String>, Vec<String>) {
+    let (patterns, designs) = input.split_once("\n\n").expect("Failed to find delimiter");
+
+    let patterns = patterns.split(", ").map(|p| p.to_string()).collect();
+    let designs = designs.lines().map(|d| d.to_string()).collect();
+
+    (patterns, designs)
+}
+
+pub struct Day19 {
+    patterns: Vec<String>,
+    designs: Vec<String>,
+}
+
+impl Day19 {
+    fn designs(&self) -> Vec<&str> {
+        self.designs.iter().map(String::as_str).collect()
+    }
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        let (patterns, designs) = parse_input(input);
+        Ok(Self { patterns, designs })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let mut comb = Combinator::new(self.patterns.clone());
+        Ok(comb.count_possible_designs(&self.designs()).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let mut comb = Combinator::new(self.patterns.clone());
+        Ok(comb.count_possible_design_ways(&self.designs()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn count_possible_designs_example() {
+        let (patterns, designs) = parse_input(EXAMPLE);
+        let designs: Vec<&str> = designs.iter().map(String::as_str).collect();
+        let mut comb = Combinator::new(patterns);
+        assert_eq!(comb.count_possible_designs(&designs), 6);
+    }
+
+    #[test]
+    fn count_possible_design_ways_example() {
+        let (patterns, designs) = parse_input(EXAMPLE);
+        let designs: Vec<&str> = designs.iter().map(String::as_str).collect();
+        let mut comb = Combinator::new(patterns);
+        assert_eq!(comb.count_possible_design_ways(&designs), 16);
+    }
+}
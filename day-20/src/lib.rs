@@ -0,0 +1,172 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use aoc_common::{search, Answer, AocError, Grid, Param, Params, Point, Solution};
+
+type PointSet = HashSet<Point>;
+pub type CheatMap = BTreeMap<i32, Vec<(Point, Point)>>;
+
+#[derive(Debug)]
+pub struct RaceTrack {
+    map: Grid<char>,
+    start: Point,
+    end: Point,
+}
+
+impl RaceTrack {
+    pub fn new(input: &str) -> Result<Self, String> {
+        let map: Grid<char> = input.parse()?;
+        let start = map.find(&'S').ok_or("Missing start")?;
+        let end = map.find(&'E').ok_or("Missing end")?;
+
+        Ok(Self { map, start, end })
+    }
+
+    pub fn find_path(&self) -> Option<(Vec<Point>, HashMap<Point, i32>)> {
+        let path = search::bfs(
+            self.start,
+            |&curr| {
+                self.map
+                    .neighbours4(curr)
+                    .filter(|&next| !self.is_wall(next))
+            },
+            |&curr| curr == self.end,
+        )?;
+
+        let path_points = (0..).zip(&path.states).map(|(i, &p)| (p, i)).collect();
+        Some((path.states, path_points))
+    }
+
+    pub fn find_cheats(
+        &self,
+        path: &[Point],
+        path_points: &HashMap<Point, i32>,
+        max_len: i32,
+    ) -> CheatMap {
+        let mut cheats = BTreeMap::new();
+
+        for (dist, &point) in path.iter().enumerate() {
+            for next_point in self.manhattan_circle(point, max_len) {
+                if let Some(&next_dist) = path_points.get(&next_point) {
+                    let saved = next_dist - dist as i32 - point.manhattan(next_point);
+                    if saved > 0 {
+                        cheats
+                            .entry(saved)
+                            .or_insert_with(Vec::new)
+                            .push((point, next_point));
+                    }
+                }
+            }
+        }
+
+        cheats
+    }
+
+    fn is_wall(&self, p: Point) -> bool {
+        self.map.get(p) == Some(&'#')
+    }
+
+    fn manhattan_circle(&self, start: Point, diameter: i32) -> PointSet {
+        let mut points = HashSet::new();
+
+        for drow in -diameter..=diameter {
+            let remaining = diameter - drow.abs();
+            for dcol in -remaining..=remaining {
+                let point = start + Point::new(drow, dcol);
+                if self.map.contains(point) && !self.is_wall(point) {
+                    points.insert(point);
+                }
+            }
+        }
+
+        points
+    }
+}
+
+pub fn count_cheats(cheats: &CheatMap, threshold: i32) -> usize {
+    cheats.range(threshold..).map(|(_, c)| c.len()).sum()
+}
+
+pub fn print_cheats(cheats: &CheatMap, threshold: i32) {
+    println!("Threshold: {}", threshold);
+    for cheat in cheats {
+        let cnt = cheat.1.len();
+        println!("There are {} cheats that save {} picoseconds", cnt, cheat.0);
+    }
+    println!("Above threshold: {}", count_cheats(cheats, threshold));
+}
+
+pub struct Day20 {
+    racetrack: RaceTrack,
+    threshold: i32,
+}
+
+impl Day20 {
+    fn count_cheats(&self, max_cheat_len: i32) -> Result<usize, AocError> {
+        let (path, path_points) = self
+            .racetrack
+            .find_path()
+            .ok_or_else(|| AocError::NoSolution("End is unreachable".to_string()))?;
+        let cheats = self
+            .racetrack
+            .find_cheats(&path, &path_points, max_cheat_len);
+        Ok(count_cheats(&cheats, self.threshold))
+    }
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+    const PARAMS: &'static [Param] = &[Param {
+        name: "threshold",
+        default: "100",
+        example: "50",
+    }];
+
+    fn parse(input: &str, params: &Params) -> Result<Self, AocError> {
+        Ok(Self {
+            racetrack: RaceTrack::new(input)?,
+            threshold: params.get("threshold")?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(self.count_cheats(2)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(self.count_cheats(20)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn cheats(max_cheat_len: i32) -> CheatMap {
+        let racetrack = RaceTrack::new(EXAMPLE).unwrap();
+        let (path, path_points) = racetrack.find_path().unwrap();
+        racetrack.find_cheats(&path, &path_points, max_cheat_len)
+    }
+
+    #[test]
+    fn count_short_cheats_example() {
+        let cheats = cheats(2);
+        assert_eq!(count_cheats(&cheats, 64), 1);
+        assert_eq!(count_cheats(&cheats, 20), 5);
+    }
+
+    #[test]
+    fn count_long_cheats_example() {
+        let cheats = cheats(20);
+        assert_eq!(count_cheats(&cheats, 76), 3);
+        assert_eq!(count_cheats(&cheats, 50), 285);
+    }
+
+    #[test]
+    fn unreachable_end() {
+        let racetrack = RaceTrack::new("#####\n#S#E#\n#####").unwrap();
+        assert!(racetrack.find_path().is_none());
+    }
+}
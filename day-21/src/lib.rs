@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use aoc_common::{Answer, AocError, Direction, Params, Point, Solution};
+
+struct Keypad {
+    map: HashMap<Point, u8>,
+    positions: HashMap<u8, Point>,
+}
+
+impl Keypad {
+    fn new<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = (Point, u8)> + Clone,
+        HashMap<Point, u8>: FromIterator<(Point, u8)>,
+        HashMap<u8, Point>: FromIterator<(u8, Point)>,
+    {
+        Self {
+            map: iter.clone().collect(),
+            positions: iter.map(|(k, v)| (v, k)).collect(),
+        }
+    }
+
+    const DOOR_KEYS: [(Point, u8); 11] = [
+        (Point::new(0, 0), b'7'), //     0   1   2
+        (Point::new(0, 1), b'8'), //   +---+---+---+
+        (Point::new(0, 2), b'9'), // 0 | 7 | 8 | 9 |
+        (Point::new(1, 0), b'4'), //   +---+---+---+
+        (Point::new(1, 1), b'5'), // 1 | 4 | 5 | 6 |
+        (Point::new(1, 2), b'6'), //   +---+---+---+
+        (Point::new(2, 0), b'1'), // 2 | 1 | 2 | 3 |
+        (Point::new(2, 1), b'2'), //   +---+---+---+
+        (Point::new(2, 2), b'3'), // 3     | 0 | A |
+        (Point::new(3, 1), b'0'), //       +---+---+
+        (Point::new(3, 2), b'A'),
+    ];
+
+    const ROBOT_KEYS: [(Point, u8); 5] = [
+        (Point::new(0, 1), b'^'), //     0   1   2
+        (Point::new(0, 2), b'A'), //       +---+---+
+        (Point::new(1, 0), b'<'), // 0     | ^ | A |
+        (Point::new(1, 1), b'v'), //   +---+---+---+
+        (Point::new(1, 2), b'>'), // 1 | < | v | > |
+                                  //   +---+---+---+
+    ];
+}
+
+pub struct KeypadChain {
+    door: Keypad,
+    robot: Keypad,
+    targets: Vec<(usize, Vec<u8>)>,
+}
+
+type Cache = HashMap<(u8, u8, usize), usize>;
+
+impl KeypadChain {
+    pub fn new(input: &str) -> Result<Self, String> {
+        let targets = input
+            .lines()
+            .map(|line| {
+                let number = line
+                    .strip_suffix('A')
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .ok_or(format!("Invalid code '{}'", line))?;
+                Ok((number, line.bytes().collect::<Vec<_>>()))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self {
+            door: Keypad::new(Keypad::DOOR_KEYS.into_iter()),
+            robot: Keypad::new(Keypad::ROBOT_KEYS.into_iter()),
+            targets,
+        })
+    }
+
+    fn traverse(&self, keypad: &Keypad, a: u8, b: u8, depth: usize, cache: &mut Cache) -> usize {
+        if let Some(&result) = cache.get(&(a, b, depth)) {
+            return result;
+        }
+
+        let from = keypad.positions[&a];
+        let to = keypad.positions[&b];
+
+        if depth == 0 {
+            return to.manhattan(from) as usize + 1;
+        }
+
+        let mut moves = Vec::new();
+
+        if from.col < to.col {
+            moves.extend([Direction::Right].repeat((to.col - from.col) as usize));
+        } else {
+            moves.extend([Direction::Left].repeat((from.col - to.col) as usize));
+        }
+
+        if from.row < to.row {
+            moves.extend([Direction::Down].repeat((to.row - from.row) as usize));
+        } else {
+            moves.extend([Direction::Up].repeat((from.row - to.row) as usize));
+        }
+
+        let result = moves
+            .iter()
+            .permutations(moves.len())
+            .filter_map(|moves| {
+                let mut p = from;
+
+                // every step has to stay on a key, never over the gap
+                for &d in &moves {
+                    p += *d;
+                    if !keypad.map.contains_key(&p) {
+                        return None;
+                    }
+                }
+
+                Some(
+                    [b'A']
+                        .into_iter()
+                        .chain(moves.into_iter().map(|d| d.arrow() as u8))
+                        .chain([b'A'])
+                        .tuple_windows()
+                        .map(|(a, b)| self.traverse(&self.robot, a, b, depth - 1, cache))
+                        .sum::<usize>(),
+                )
+            })
+            .min()
+            .expect("failed to find move set");
+
+        cache.insert((a, b, depth), result);
+        result
+    }
+
+    pub fn solve(&self, depth: usize) -> usize {
+        let mut cache = Cache::default();
+
+        self.targets
+            .iter()
+            .map(|(number, seq)| {
+                number
+                    * [b'A']
+                        .iter()
+                        .chain(seq.iter())
+                        .tuple_windows()
+                        .map(|(&a, &b)| self.traverse(&self.door, a, b, depth, &mut cache))
+                        .sum::<usize>()
+            })
+            .sum::<usize>()
+    }
+}
+
+pub struct Day21 {
+    chain: KeypadChain,
+}
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        Ok(Self {
+            chain: KeypadChain::new(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(self.chain.solve(2).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(self.chain.solve(25).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn complexity_example() {
+        let chain = KeypadChain::new(EXAMPLE).unwrap();
+        assert_eq!(chain.solve(2), 126384);
+        assert_eq!(chain.solve(25), 154115708116294);
+    }
+}
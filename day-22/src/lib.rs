@@ -0,0 +1,186 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{Answer, AocError, Params, Part, Solution};
+
+#[inline]
+fn last_digit(num: i64) -> i64 {
+    num % 10
+}
+
+#[inline]
+fn mix(num: i64, secret: i64) -> i64 {
+    num ^ secret
+}
+
+#[inline]
+fn prune(num: i64) -> i64 {
+    num % 16777216
+}
+
+struct Prices {
+    curr: i64,
+    curr_price: i64,
+    prev_price: i64,
+    changes: VecDeque<i32>,
+    n: usize,
+    iteration: usize,
+}
+
+impl Prices {
+    fn new(init: i64, n: usize) -> Self {
+        Self {
+            curr: init,
+            curr_price: last_digit(init),
+            prev_price: last_digit(init),
+            changes: VecDeque::new(),
+            n,
+            iteration: 0,
+        }
+    }
+
+    fn next_secret(&self) -> i64 {
+        let mut secret = self.curr;
+
+        let result = secret * 64;
+        secret = mix(result, secret);
+        secret = prune(secret);
+
+        let result = secret / 32;
+        secret = mix(result, secret);
+        secret = prune(secret);
+
+        let result = secret * 2048;
+        secret = mix(result, secret);
+        secret = prune(secret);
+
+        secret
+    }
+}
+
+impl Iterator for Prices {
+    type Item = (i64, i64, Vec<i32>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iteration > self.n {
+            return None;
+        }
+
+        let changes: Vec<i32> = Vec::from(self.changes.clone());
+        let result = (self.curr, self.curr_price, changes);
+
+        self.prev_price = self.curr_price;
+        self.curr = self.next_secret();
+        self.curr_price = last_digit(self.curr);
+
+        self.changes
+            .push_back((self.curr_price - self.prev_price) as i32);
+
+        if self.changes.len() > 4 {
+            self.changes.pop_front();
+        }
+
+        self.iteration += 1;
+
+        Some(result)
+    }
+}
+
+pub fn total_secret(input: &str) -> i64 {
+    input
+        .lines()
+        .map(|line| {
+            let init = line.parse::<i64>().expect("Invalid secret number");
+            Prices::new(init, 2000)
+        })
+        .map(|prices| {
+            let mut result = 0;
+            for (secret, _, _) in prices {
+                result = secret;
+            }
+            result
+        })
+        .sum()
+}
+
+pub fn max_bananas(input: &str) -> i64 {
+    let initial_secrets: Vec<i64> = input
+        .lines()
+        .filter_map(|line| line.parse::<i64>().ok())
+        .collect();
+
+    let size = initial_secrets.len();
+
+    let mut map: HashMap<String, Vec<Option<i64>>> = HashMap::new();
+
+    initial_secrets
+        .iter()
+        .map(|&init| Prices::new(init, 2000))
+        .enumerate()
+        .for_each(|(i, prices)| {
+            for (_, price, prev) in prices {
+                if prev.len() < 4 {
+                    continue;
+                }
+
+                let key = format!("{:?}", prev);
+                map.entry(key).or_insert_with(|| vec![None; size])[i].get_or_insert(price);
+            }
+        });
+
+    map.values()
+        .map(|v| {
+            let sum: i64 = v.iter().filter_map(|&p| p).sum();
+            sum
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day22 {
+    input: String,
+}
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        Ok(Self {
+            input: input.to_string(),
+        })
+    }
+
+    // part 2 has its own example buyers
+    fn example(part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => Some(Self::EXAMPLE),
+            Part::Two => Some(include_str!("../example2.txt")),
+        }
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(total_secret(&self.input).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(max_bananas(&self.input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn total_secret_example() {
+        assert_eq!(total_secret(EXAMPLE), 37327623);
+    }
+
+    #[test]
+    fn max_bananas_example() {
+        assert_eq!(max_bananas(EXAMPLE2), 23);
+    }
+}
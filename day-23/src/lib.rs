@@ -0,0 +1,139 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use aoc_common::{Answer, AocError, Params, Solution};
+
+fn get_computer_links(input: &str) -> Vec<(&str, &str)> {
+    input
+        .lines()
+        .filter_map(|line| line.split_once('-'))
+        .collect()
+}
+
+pub fn count_t_computers(input: &str) -> usize {
+    let computer_links = get_computer_links(input);
+
+    let mut neighbors: HashSet<(&str, &str)> = HashSet::new();
+    let mut vs: HashSet<&str> = HashSet::new();
+
+    for (a, b) in &computer_links {
+        vs.insert(a);
+        vs.insert(b);
+        neighbors.insert((a, b));
+        neighbors.insert((b, a));
+    }
+
+    let mut cnt = 0;
+
+    for (a, b) in &computer_links {
+        for v in &vs {
+            if !a.starts_with("t") && !b.starts_with("t") && !v.starts_with("t") {
+                continue;
+            }
+            if neighbors.contains(&(v, a)) && neighbors.contains(&(b, v)) {
+                cnt += 1;
+            }
+        }
+    }
+
+    cnt / 3
+}
+
+fn bron_kerbosch<'a>(
+    mut p: HashSet<&'a str>,
+    r: HashSet<&'a str>,
+    mut x: HashSet<&'a str>,
+    n: &HashMap<&'a str, HashSet<&'a str>>,
+) -> Vec<HashSet<&'a str>> {
+    if p.is_empty() && x.is_empty() {
+        return vec![r];
+    }
+
+    let mut res = Vec::new();
+
+    while let Some(v) = pop(&mut p) {
+        let mut nr = r.clone();
+        nr.insert(v);
+
+        let np = p.clone().intersection(&n[&v]).cloned().collect();
+        let nx = x.clone().intersection(&n[&v]).cloned().collect();
+
+        res.extend(bron_kerbosch(np, nr, nx, n));
+
+        x.insert(v);
+    }
+
+    res
+}
+
+fn pop<T>(s: &mut HashSet<T>) -> Option<T>
+where
+    T: Hash + Eq + Clone,
+{
+    let e = s.iter().next().cloned()?;
+    s.take(&e)
+}
+
+pub fn lan_party_password(input: &str) -> String {
+    let computer_links = get_computer_links(input);
+
+    let mut neighbors: HashMap<&str, HashSet<&str>> = HashMap::new();
+    let mut vs: HashSet<&str> = HashSet::new();
+
+    for (a, b) in computer_links.iter() {
+        vs.insert(a);
+        vs.insert(b);
+        neighbors.entry(a).or_default().insert(b);
+        neighbors.entry(b).or_default().insert(a);
+    }
+
+    let mut computers: Vec<String> = bron_kerbosch(vs, HashSet::new(), HashSet::new(), &neighbors)
+        .iter()
+        .max_by(|c1, c2| c1.len().cmp(&c2.len()))
+        .map(|vs| vs.iter().map(|v| v.to_string()).collect())
+        .unwrap_or_default();
+
+    computers.sort();
+
+    computers.join(",")
+}
+
+pub struct Day23 {
+    input: String,
+}
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        Ok(Self {
+            input: input.to_string(),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(count_t_computers(&self.input).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(lan_party_password(&self.input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn count_t_computers_example() {
+        assert_eq!(count_t_computers(EXAMPLE), 7);
+    }
+
+    #[test]
+    fn lan_party_password_example() {
+        assert_eq!(lan_party_password(EXAMPLE), "co,de,ka,ta");
+    }
+}
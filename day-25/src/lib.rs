@@ -0,0 +1,95 @@
+use aoc_common::{Answer, AocError, Params, Part, Solution};
+
+type Heights = [u8; 5];
+
+fn parse_lock(shape: &str) -> Heights {
+    parse_heights(shape.lines())
+}
+
+fn parse_key(shape: &str) -> Heights {
+    parse_heights(shape.lines().rev())
+}
+
+fn parse_heights<'a>(iter: impl Iterator<Item = &'a str>) -> Heights {
+    iter.skip(1).fold([0; 5], |mut heights, line| {
+        line.chars().enumerate().for_each(|(i, ch)| {
+            if ch == '#' {
+                heights[i] += 1;
+            }
+        });
+        heights
+    })
+}
+
+pub fn parse_input(input: &str) -> (Vec<Heights>, Vec<Heights>) {
+    input
+        .split("\n\n")
+        .fold((Vec::new(), Vec::new()), |(mut locks, mut keys), shape| {
+            if shape.starts_with("#####") {
+                locks.push(parse_lock(shape));
+            } else if shape.starts_with(".....") {
+                keys.push(parse_key(shape));
+            }
+            (locks, keys)
+        })
+}
+
+pub fn format_locks_and_keys(locks: &[Heights], keys: &[Heights]) -> String {
+    let mut s = String::from("Locks:");
+    locks.iter().for_each(|lock| s += &format!("\n{:?}", lock));
+
+    s += "\nKeys:";
+    keys.iter().for_each(|key| s += &format!("\n{:?}", key));
+    s
+}
+
+fn is_compatible(lock: &Heights, key: &Heights) -> bool {
+    lock.iter().zip(key).all(|(l, k)| l + k <= 5)
+}
+
+pub fn unique_pairs(locks: &[Heights], keys: &[Heights]) -> usize {
+    locks
+        .iter()
+        .flat_map(|lock| keys.iter().map(move |key| (lock, key)))
+        .filter(|(lock, key)| is_compatible(lock, key))
+        .count()
+}
+
+pub struct Day25 {
+    locks: Vec<Heights>,
+    keys: Vec<Heights>,
+}
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const EXAMPLE: &'static str = include_str!("../example.txt");
+    const PARTS: &'static [Part] = &[Part::One];
+
+    fn parse(input: &str, _params: &Params) -> Result<Self, AocError> {
+        let (locks, keys) = parse_input(input);
+        Ok(Self { locks, keys })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(unique_pairs(&self.locks, &self.keys).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Err(AocError::NoSolution(
+            "Day 25 has no second part".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn unique_pairs_example() {
+        let (locks, keys) = parse_input(EXAMPLE);
+        assert_eq!(unique_pairs(&locks, &keys), 3);
+    }
+}